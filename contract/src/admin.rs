@@ -0,0 +1,390 @@
+/*
+ * Axelar Gateway Administration
+ *
+ */
+use crate::auth_module::ext_auth_module;
+use crate::events::{
+    AdminActionEvent, AdminOverrideEvent, OwnershipTransferCancelledEvent, UpgradeStagedEvent,
+    UpgradedEvent,
+};
+use crate::types::{BatchEncoding, Bytes, Bytes32};
+use crate::utils::{self, abi_decode};
+use crate::{Axelar, AxelarExt};
+use ethabi::ParamType;
+use near_contract_tools::owner::*;
+use near_contract_tools::pause::*;
+use near_contract_tools::standard::nep297::Event;
+use near_sdk::borsh::{self, BorshDeserialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::{env, near_bindgen, AccountId, Gas, Promise, PromiseOrValue};
+
+/// Defining a constant string called SELECTOR_UPGRADE.
+pub const SELECTOR_UPGRADE: &str = "upgrade";
+/// Defining a constant string called SELECTOR_TRANSFER_OWNERSHIP.
+pub const SELECTOR_TRANSFER_OWNERSHIP: &str = "transferOwnership";
+/// Defining a constant string called SELECTOR_PAUSE.
+pub const SELECTOR_PAUSE: &str = "pause";
+/// Defining a constant string called SELECTOR_UNPAUSE.
+pub const SELECTOR_UNPAUSE: &str = "unpause";
+/// Defining a constant string called SELECTOR_SET_RETENTION.
+pub const SELECTOR_SET_RETENTION: &str = "setRetention";
+
+/// The commands that are only honored with a proof from the current operators.
+pub const ADMIN_SELECTORS: [&str; 5] = [
+    SELECTOR_UPGRADE,
+    SELECTOR_TRANSFER_OWNERSHIP,
+    SELECTOR_PAUSE,
+    SELECTOR_UNPAUSE,
+    SELECTOR_SET_RETENTION,
+];
+
+/// The gas attached to the `migrate` call of the upgraded code.
+const MIGRATE_GAS: Gas = Gas(50_000_000_000_000);
+
+/// The version of the state layout, stored under `STATE_VERSION_KEY`.
+pub(crate) const STATE_VERSION: u8 = 1;
+/// The storage key of the state version, deployments without it use the layout of `AxelarV0`.
+pub(crate) const STATE_VERSION_KEY: &[u8] = b"state_version";
+
+/// `AxelarV0` is the state layout of version 1.0.0 of the gateway, from before the state was
+/// versioned.
+#[derive(BorshDeserialize)]
+struct AxelarV0 {
+    current_epoch: u64,
+    hash_for_epoch: LookupMap<u64, [u8; 32]>,
+    epoch_for_hash: LookupMap<[u8; 32], u64>,
+    prefix_command_executed: [u8; 32],
+    prefix_contract_call_approved: [u8; 32],
+    bool_state: LookupMap<[u8; 32], bool>,
+}
+
+/// Axelar Gateway Administration Implementation
+#[near_bindgen]
+impl Axelar {
+    /// `migrate` brings the state of a previous version up to the current layout. It is called by
+    /// the `upgrade` command on the new code, and can be called again safely.
+    ///
+    /// Returns:
+    ///
+    /// The migrated contract.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let contract = match env::storage_read(STATE_VERSION_KEY).as_deref() {
+            None => {
+                let state: AxelarV0 =
+                    env::state_read().unwrap_or_else(|| env::panic_str("Contract not initialized"));
+
+                // Operator sets of version 1.0.0 were never pruned, keep the default settings and
+                // were only stored by hash, so `operators_for_epoch` returns `None` for them
+                Self {
+                    current_epoch: state.current_epoch,
+                    hash_for_epoch: state.hash_for_epoch,
+                    epoch_for_hash: state.epoch_for_hash,
+                    prefix_command_executed: state.prefix_command_executed,
+                    prefix_contract_call_approved: state.prefix_contract_call_approved,
+                    bool_state: state.bool_state,
+                    ..Self::default()
+                }
+            }
+            Some([STATE_VERSION]) => {
+                env::state_read().unwrap_or_else(|| env::panic_str("Contract not initialized"))
+            }
+            Some(_) => env::panic_str("Unknown state version"),
+        };
+
+        // Proposals of the `near_contract_tools` owner slot can no longer be accepted
+        if let Some(proposed_owner) = Self::slot_proposed_owner().take() {
+            OwnershipTransferCancelledEvent {
+                owner: Self::slot_owner()
+                    .read()
+                    .map(|x| x.to_string())
+                    .unwrap_or_default(),
+                proposed_owner: proposed_owner.to_string(),
+            }
+            .emit();
+        }
+
+        env::storage_write(STATE_VERSION_KEY, &[STATE_VERSION]);
+
+        contract
+    }
+
+    /// `admin_action_count` returns how many calls the owner has made, so monitoring can alert on
+    /// manual overrides
+    ///
+    /// Returns:
+    ///
+    /// The number of admin actions.
+    pub fn admin_action_count(&self) -> u64 {
+        self.admin_action_count
+    }
+
+    /// `is_upgrade_staged` returns whether code is staged for an `upgrade` command
+    ///
+    /// Arguments:
+    ///
+    /// * `code_hash`: The sha256 hash of the code.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    pub fn is_upgrade_staged(&self, code_hash: Bytes32) -> bool {
+        self.staged_code.contains_key(&code_hash.0)
+    }
+
+    /// `stage_upgrade` stores contract code, so an `upgrade` command signed by the operators can
+    /// deploy it by hash. Anyone can stage code, the caller pays for its storage and the rest of
+    /// the deposit is refunded.
+    ///
+    /// Arguments:
+    ///
+    /// * `code`: The contract code, as `0x`-prefixed hex or Base64.
+    ///
+    /// Returns:
+    ///
+    /// The sha256 hash of the code.
+    #[payable]
+    pub fn stage_upgrade(&mut self, code: Bytes) -> Bytes32 {
+        let code_hash: [u8; 32] = env::sha256(&code.0).try_into().unwrap();
+
+        if self.staged_code.contains_key(&code_hash) {
+            env::panic_str("Upgrade already staged");
+        }
+
+        let storage_usage = env::storage_usage();
+
+        self.staged_code.insert(&code_hash, &code.0);
+
+        let storage_cost =
+            (env::storage_usage() - storage_usage) as u128 * env::storage_byte_cost();
+        let deposit = env::attached_deposit();
+
+        if deposit < storage_cost {
+            env::panic_str("Insufficient deposit");
+        }
+
+        if deposit > storage_cost {
+            Promise::new(env::predecessor_account_id()).transfer(deposit - storage_cost);
+        }
+
+        UpgradeStagedEvent {
+            code_hash: utils::to_eth_hex_string(code_hash),
+            size: code.0.len() as u64,
+        }
+        .emit();
+
+        Bytes32(code_hash)
+    }
+
+    // Only Owner functions
+
+    /// `pause` stops contract calls in and out of the gateway, commands that approve contract
+    /// calls are held back until the gateway is unpaused
+    #[payable]
+    pub fn pause(&mut self) {
+        self.internal_admin_action("pause");
+        Pause::pause(self);
+    }
+
+    /// `unpause` resumes contract calls in and out of the gateway
+    #[payable]
+    pub fn unpause(&mut self) {
+        self.internal_admin_action("unpause");
+        Pause::unpause(self);
+    }
+
+    /// `admin_override_count` returns how many owner calls approved contract calls or rotated the
+    /// operators, which the operators do otherwise
+    ///
+    /// Returns:
+    ///
+    /// The number of admin overrides.
+    pub fn admin_override_count(&self) -> u64 {
+        self.admin_override_count
+    }
+
+    // Internal functions
+
+    /// It checks that the caller is the owner, and records the call with an `AdminActionEvent`
+    ///
+    /// Arguments:
+    ///
+    /// * `method`: The name of the method that was called.
+    pub(crate) fn internal_admin_action(&mut self, method: &str) {
+        Self::require_owner();
+
+        self.admin_action_count += 1;
+
+        AdminActionEvent {
+            method: method.to_string(),
+            caller: env::predecessor_account_id().to_string(),
+            args_hash: utils::to_eth_hex_string(utils::keccak256(env::input().unwrap_or_default())),
+            count: self.admin_action_count,
+        }
+        .emit();
+    }
+
+    /// It records an owner call that overrides the operators with an `AdminActionEvent` and an
+    /// `AdminOverrideEvent`
+    ///
+    /// Arguments:
+    ///
+    /// * `method`: The name of the method that was called.
+    pub(crate) fn internal_admin_override(&mut self, method: &str) {
+        self.internal_admin_action(method);
+
+        self.admin_override_count += 1;
+
+        AdminOverrideEvent {
+            method: method.to_string(),
+            caller: env::predecessor_account_id().to_string(),
+            count: self.admin_override_count,
+        }
+        .emit();
+    }
+
+    /// It executes an administrative command signed by the current operators
+    ///
+    /// Arguments:
+    ///
+    /// * `command`: The selector of the command, one of `ADMIN_SELECTORS`.
+    /// * `params`: The parameters of the command, ABI-encoded or, in a Borsh batch, Borsh-encoded
+    ///   with the same types:
+    ///   - `upgrade`: `(bytes32 codeHash, bytes migrationArgs)`, `migrate` is always called, with
+    ///     `{}` if the arguments are empty.
+    ///   - `transferOwnership`: `(string newOwner)`.
+    ///   - `pause` and `unpause`: none.
+    ///   - `setRetention`: `(uint256 oldKeyRetention)`, a `u64` in a Borsh batch.
+    /// * `encoding`: The format of the batch the command is in.
+    /// * `expected_epoch`: The epoch of the operators that signed the command, checked by the auth
+    ///   module for commands forwarded to it.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value, or the promise of the auth module for commands forwarded to it.
+    pub(crate) fn internal_execute_admin_command(
+        &mut self,
+        command: &str,
+        params: Vec<u8>,
+        encoding: BatchEncoding,
+        expected_epoch: u64,
+    ) -> PromiseOrValue<bool> {
+        let success = match command {
+            SELECTOR_UPGRADE => {
+                let decoded = match encoding {
+                    BatchEncoding::Abi => {
+                        abi_decode(&params, &[ParamType::FixedBytes(32), ParamType::Bytes])
+                            .ok()
+                            .map(|tokens| {
+                                (
+                                    tokens[0]
+                                        .clone()
+                                        .into_fixed_bytes()
+                                        .unwrap()
+                                        .try_into()
+                                        .unwrap(),
+                                    tokens[1].clone().into_bytes().unwrap(),
+                                )
+                            })
+                    }
+                    BatchEncoding::Borsh => BorshDeserialize::try_from_slice(&params).ok(),
+                };
+
+                let (code_hash, migration_args): ([u8; 32], Vec<u8>) = match decoded {
+                    Some(decoded) => decoded,
+                    None => return PromiseOrValue::Value(false),
+                };
+
+                let code = match self.staged_code.remove(&code_hash) {
+                    Some(code) => code,
+                    None => return PromiseOrValue::Value(false),
+                };
+
+                let args = if migration_args.is_empty() {
+                    b"{}".to_vec()
+                } else {
+                    migration_args.clone()
+                };
+
+                // The new code has to read the state before anything else does
+                Promise::new(env::current_account_id())
+                    .deploy_contract(code)
+                    .function_call("migrate".to_string(), args, 0, MIGRATE_GAS);
+
+                UpgradedEvent {
+                    code_hash: utils::to_eth_hex_string(code_hash),
+                    has_migration_args: !migration_args.is_empty(),
+                }
+                .emit();
+
+                true
+            }
+            SELECTOR_TRANSFER_OWNERSHIP => {
+                let new_owner = match encoding {
+                    BatchEncoding::Abi => abi_decode(&params, &[ParamType::String])
+                        .ok()
+                        .and_then(|tokens| tokens[0].clone().into_string()),
+                    BatchEncoding::Borsh => String::try_from_slice(&params).ok(),
+                }
+                .and_then(|x| x.parse::<AccountId>().ok());
+
+                match new_owner {
+                    Some(new_owner) => {
+                        self.internal_transfer_ownership(Some(new_owner));
+
+                        true
+                    }
+                    None => false,
+                }
+            }
+            SELECTOR_PAUSE => {
+                if !Self::is_paused() {
+                    Pause::pause(self);
+                }
+
+                true
+            }
+            SELECTOR_UNPAUSE => {
+                if Self::is_paused() {
+                    Pause::unpause(self);
+                }
+
+                true
+            }
+            SELECTOR_SET_RETENTION => {
+                let old_key_retention = match encoding {
+                    BatchEncoding::Abi => abi_decode(&params, &[ParamType::Uint(256)])
+                        .ok()
+                        .and_then(|tokens| tokens[0].clone().into_uint())
+                        .filter(|x| x.bits() <= 64)
+                        .map(|x| x.as_u64()),
+                    BatchEncoding::Borsh => u64::try_from_slice(&params).ok(),
+                }
+                .filter(|x| *x != 0);
+
+                let old_key_retention = match old_key_retention {
+                    Some(old_key_retention) => old_key_retention,
+                    None => return PromiseOrValue::Value(false),
+                };
+
+                match self.auth_module.clone() {
+                    None => self
+                        .internal_set_old_key_retention(old_key_retention)
+                        .is_ok(),
+                    Some(auth_module) => {
+                        return PromiseOrValue::Promise(
+                            ext_auth_module::ext(auth_module).set_old_key_retention_from_gateway(
+                                old_key_retention,
+                                expected_epoch,
+                            ),
+                        );
+                    }
+                }
+            }
+            _ => false,
+        };
+
+        PromiseOrValue::Value(success)
+    }
+}
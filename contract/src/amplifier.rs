@@ -0,0 +1,297 @@
+use crate::auth_module::{ext_auth_module, Authentication};
+use crate::events::{MessageApprovedEvent, MessageExecutedEvent};
+use crate::types::{Bytes, Bytes32, Message, WeightedSigners, BORSH_ENCODING_VERSION};
+use crate::utils::{self, abi_encode, keccak256};
+use crate::{Axelar, AxelarExt};
+use ethabi::Token;
+use near_sdk::env::{self, predecessor_account_id};
+
+use near_contract_tools::pause::*;
+use near_contract_tools::standard::nep297::Event;
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::{near_bindgen, Promise, PromiseOrValue};
+
+/// Defining a constant string called SELECTOR_APPROVE_MESSAGES.
+pub const SELECTOR_APPROVE_MESSAGES: &str = "approveMessages";
+/// Defining a constant string called SELECTOR_ROTATE_SIGNERS.
+pub const SELECTOR_ROTATE_SIGNERS: &str = "rotateSigners";
+
+/// Axelar Amplifier Gateway Implementation
+#[near_bindgen]
+impl Axelar {
+    /// It validates the operators' proof over the messages, and then approves every message that
+    /// has not been approved before
+    ///
+    /// The operators sign `keccak256(abi.encode("approveMessages", messages))` in the signing domain
+    /// of the gateway, see `signing_domain`, where each message is encoded as
+    /// `(string sourceChain, string messageId, string sourceAddress, string contractAddress,
+    /// bytes32 payloadHash)`. In the `Legacy` domain they sign its Ethereum signed message hash, in
+    /// the `Near` domain `keccak256(domain_separator || hash)`.
+    ///
+    /// Arguments:
+    ///
+    /// * `messages`: The messages to approve.
+    /// * `proof`: The encoded proof, as `0x`-prefixed hex or Base64.
+    #[payable]
+    pub fn approve_messages(&mut self, messages: Vec<Message>, proof: Bytes) -> PromiseOrValue<()> {
+        Self::require_unpaused();
+
+        if messages.is_empty() {
+            env::panic_str("Invalid messages");
+        }
+
+        let data_hash = keccak256(abi_encode(vec![
+            Token::String(SELECTOR_APPROVE_MESSAGES.to_string()),
+            Token::Array(messages.iter().map(Message::to_token).collect()),
+        ]));
+
+        match self.internal_authenticate(data_hash, proof.0) {
+            Authentication::Verified(_) => {
+                self.internal_approve_messages(messages);
+
+                PromiseOrValue::Value(())
+            }
+            Authentication::Pending(promise) => PromiseOrValue::Promise(promise.then(
+                Self::ext(env::current_account_id()).on_approve_messages_authenticated(messages),
+            )),
+        }
+    }
+
+    /// It validates the proof of the current operators over the new operator set, and then
+    /// rotates to it
+    ///
+    /// The operators sign `keccak256(abi.encode("rotateSigners", operators, weights, threshold,
+    /// nonce))` in the signing domain of the gateway, like the messages of `approve_messages`.
+    ///
+    /// Arguments:
+    ///
+    /// * `new_signers`: The new operator set. Rotating to an operator set that was registered
+    ///   before requires a nonce that was not used with it yet.
+    /// * `proof`: The encoded proof, as `0x`-prefixed hex or Base64.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    #[payable]
+    pub fn rotate_signers(
+        &mut self,
+        new_signers: WeightedSigners,
+        proof: Bytes,
+    ) -> PromiseOrValue<bool> {
+        let mut tokens = vec![Token::String(SELECTOR_ROTATE_SIGNERS.to_string())];
+        tokens.extend(new_signers.to_tokens());
+        tokens.push(Token::FixedBytes(new_signers.nonce.0.to_vec()));
+
+        let data_hash = keccak256(abi_encode(tokens));
+
+        match self.internal_authenticate(data_hash, proof.0) {
+            Authentication::Verified(is_latest_signers) => {
+                if !is_latest_signers {
+                    env::panic_str("Not latest signers");
+                }
+
+                PromiseOrValue::Value(self.internal_rotate_signers(new_signers, true))
+            }
+            Authentication::Pending(promise) => PromiseOrValue::Promise(promise.then(
+                Self::ext(env::current_account_id()).on_rotate_signers_authenticated(new_signers),
+            )),
+        }
+    }
+
+    // Callbacks
+
+    /// It approves the messages once the auth module checked their proof
+    ///
+    /// Arguments:
+    ///
+    /// * `messages`: The messages to approve.
+    #[private]
+    pub fn on_approve_messages_authenticated(&mut self, messages: Vec<Message>) {
+        self.internal_resolve_authentication();
+
+        self.internal_approve_messages(messages);
+    }
+
+    /// It rotates the signers of the auth module once it checked the proof of the current operators
+    ///
+    /// Arguments:
+    ///
+    /// * `new_signers`: The new operator set.
+    ///
+    /// Returns:
+    ///
+    /// A promise of the auth module rotation.
+    #[private]
+    pub fn on_rotate_signers_authenticated(&mut self, new_signers: WeightedSigners) -> Promise {
+        let expected_epoch = self
+            .internal_resolve_authentication()
+            .unwrap_or_else(|| env::panic_str("Not latest signers"));
+
+        let mut params = vec![BORSH_ENCODING_VERSION];
+        params.extend(new_signers.try_to_vec().unwrap());
+
+        ext_auth_module::ext(self.auth_module())
+            .transfer_operatorship_from_gateway(Bytes(params), expected_epoch)
+    }
+
+    // Payable functions
+
+    /// If the message is approved for the calling contract, then set the approval to false, mark
+    /// the message as executed and return true
+    ///
+    /// Arguments:
+    ///
+    /// * `source_chain`: The chain that the message originated from.
+    /// * `message_id`: The id of the message on the source chain.
+    /// * `source_address`: The address of the sender on the source chain.
+    /// * `payload_hash`: The hash of the message payload.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    #[payable]
+    pub fn validate_message(
+        &mut self,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: Bytes32,
+    ) -> bool {
+        Self::require_unpaused();
+
+        let command_id = Message::command_id(&source_chain, &message_id);
+
+        let key = self.internal_get_is_contract_call_approved_key(
+            command_id,
+            source_chain.clone(),
+            source_address,
+            predecessor_account_id().to_string(),
+            payload_hash.0,
+        );
+
+        let valid = self.bool_state.get(&key).unwrap_or(false);
+
+        if valid {
+            self.bool_state.insert(&key, &false);
+
+            let executed_key = self.internal_get_is_message_executed_key(command_id);
+            self.bool_state.insert(&executed_key, &true);
+
+            let event = MessageExecutedEvent {
+                command_id: utils::to_eth_hex_string(command_id),
+                source_chain,
+                message_id,
+            };
+
+            Event::emit(&event);
+        }
+
+        valid
+    }
+
+    // View functions
+
+    /// It returns a boolean value indicating whether a message is approved and not yet executed
+    ///
+    /// Arguments:
+    ///
+    /// * `source_chain`: The chain that the message originated from.
+    /// * `message_id`: The id of the message on the source chain.
+    /// * `source_address`: The address of the sender on the source chain.
+    /// * `contract_address`: The NEAR account the message is sent to.
+    /// * `payload_hash`: The hash of the message payload.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    pub fn is_message_approved(
+        &self,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        contract_address: String,
+        payload_hash: Bytes32,
+    ) -> bool {
+        let key = self.internal_get_is_contract_call_approved_key(
+            Message::command_id(&source_chain, &message_id),
+            source_chain,
+            source_address,
+            contract_address,
+            payload_hash.0,
+        );
+
+        self.bool_state.get(&key).unwrap_or(false)
+    }
+
+    /// It returns a boolean value indicating whether an approved message has been executed
+    ///
+    /// Arguments:
+    ///
+    /// * `source_chain`: The chain that the message originated from.
+    /// * `message_id`: The id of the message on the source chain.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    pub fn is_message_executed(&self, source_chain: String, message_id: String) -> bool {
+        let key = self
+            .internal_get_is_message_executed_key(Message::command_id(&source_chain, &message_id));
+
+        self.bool_state.get(&key).unwrap_or(false)
+    }
+
+    // Internal functions
+
+    /// It approves every message that has not been approved before
+    ///
+    /// Arguments:
+    ///
+    /// * `messages`: The messages whose proof has been checked.
+    fn internal_approve_messages(&mut self, messages: Vec<Message>) {
+        for message in messages {
+            let command_id = Message::command_id(&message.source_chain, &message.message_id);
+
+            if self.is_command_executed(Bytes32(command_id)) {
+                continue;
+            }
+
+            self.internal_set_command_executed(command_id, true);
+            self.internal_set_contract_call_approved(
+                command_id,
+                message.source_chain.clone(),
+                message.source_address.clone(),
+                message.contract_address.clone(),
+                message.payload_hash.0,
+            );
+
+            let event = MessageApprovedEvent {
+                command_id: utils::to_eth_hex_string(command_id),
+                source_chain: message.source_chain,
+                message_id: message.message_id,
+                source_address: message.source_address,
+                contract_address: message.contract_address,
+                payload_hash: message.payload_hash.to_string(),
+            };
+
+            Event::emit(&event);
+        }
+    }
+
+    /// `internal_get_is_message_executed_key` returns the key of the executed flag of a message
+    ///
+    /// Arguments:
+    ///
+    /// * `command_id`: The command ID the message is tracked under.
+    ///
+    /// Returns:
+    ///
+    /// The keccak256 hash of the encoded prefix_message_executed and command_id.
+    fn internal_get_is_message_executed_key(&self, command_id: [u8; 32]) -> [u8; 32] {
+        let encoded = abi_encode(vec![
+            Token::Bytes(self.prefix_message_executed.to_vec()),
+            Token::FixedBytes(command_id.to_vec()),
+        ]);
+
+        keccak256(encoded)
+    }
+}
@@ -0,0 +1,220 @@
+/*
+ * Axelar Auth Module
+ *
+ */
+use crate::events::AuthModuleUpdatedEvent;
+use crate::types::{Bytes, Bytes32, ProofReport};
+use crate::{Axelar, AxelarExt};
+use near_contract_tools::standard::nep297::Event;
+use near_sdk::{env, ext_contract, near_bindgen, serde_json, AccountId, Promise, PromiseResult};
+
+/// The interface of a contract that verifies proofs and manages operator sets for the gateway.
+///
+/// The gateway contract implements it itself, so the same contract can be deployed as an auth
+/// module. The gateway has to be the owner of its auth module to transfer operatorship and set
+/// the key retention, it passes the epoch of the operators that signed the command so the auth
+/// module rejects it once they rotated.
+#[allow(dead_code)]
+#[ext_contract(ext_auth_module)]
+pub trait AuthModule {
+    fn validate_proof(&self, message_hash: Bytes32, proof: Bytes) -> bool;
+
+    fn inspect_proof(&self, message_hash: Bytes32, proof: Bytes) -> ProofReport;
+
+    fn transfer_operatorship(
+        &mut self,
+        params: Bytes,
+        enforce_rotation_delay: Option<bool>,
+    ) -> bool;
+
+    fn set_minimum_rotation_delay(&mut self, minimum_rotation_delay: u64);
+
+    fn transfer_operatorship_from_gateway(&mut self, params: Bytes, expected_epoch: u64) -> bool;
+
+    fn set_old_key_retention_from_gateway(&mut self, old_key_retention: u64, expected_epoch: u64);
+}
+
+/// `Authentication` is the outcome of checking a proof through the auth module.
+///
+/// Properties:
+///
+/// * `Verified`: The proof was checked in this contract, and whether it is from the current
+///   operators.
+/// * `Pending`: The proof is being checked by the auth module. The promise has to be followed by
+///   a callback that calls `internal_resolve_authentication`.
+pub(crate) enum Authentication {
+    Verified(bool),
+    Pending(Promise),
+}
+
+/// Axelar Auth Module Implementation
+#[near_bindgen]
+impl Axelar {
+    /// `auth_module` returns the account id of the contract that checks proofs for the gateway
+    ///
+    /// Returns:
+    ///
+    /// The account id of the auth module, the current account if weighted auth runs in this contract.
+    pub fn auth_module(&self) -> AccountId {
+        self.auth_module
+            .clone()
+            .unwrap_or_else(env::current_account_id)
+    }
+
+    // Only owner
+
+    /// `set_auth_module` switches the contract that checks proofs for the gateway, so the auth
+    /// scheme can be upgraded independently of gateway state
+    ///
+    /// While an auth module is set, the operator set views and setters of this contract panic,
+    /// the operator sets are read and managed on the auth module instead. The owner manages them
+    /// through `transfer_auth_module_operatorship` and `set_auth_module_minimum_rotation_delay`.
+    ///
+    /// Arguments:
+    ///
+    /// * `auth_module`: The account id of the new auth module, or the current account to check
+    ///   proofs in this contract.
+    #[payable]
+    pub fn set_auth_module(&mut self, auth_module: AccountId) {
+        self.internal_admin_action("set_auth_module");
+
+        let event = AuthModuleUpdatedEvent {
+            previous_auth_module: self.auth_module().to_string(),
+            new_auth_module: auth_module.to_string(),
+        };
+
+        self.auth_module = if auth_module == env::current_account_id() {
+            None
+        } else {
+            Some(auth_module)
+        };
+
+        event.emit();
+    }
+
+    /// `transfer_auth_module_operatorship` lets the owner rotate the operators of the auth module,
+    /// like `transfer_operatorship` does while proofs are checked in this contract
+    ///
+    /// The owner is not bound by the minimum rotation delay of the auth module, so it can override
+    /// it in an emergency.
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The ABI-encoded operators, weights and threshold, or Borsh-encoded
+    ///   `WeightedSigners`, as `0x`-prefixed hex or Base64.
+    ///
+    /// Returns:
+    ///
+    /// The promise of the auth module rotation.
+    #[payable]
+    pub fn transfer_auth_module_operatorship(&mut self, params: Bytes) -> Promise {
+        self.internal_admin_override("transfer_auth_module_operatorship");
+
+        ext_auth_module::ext(self.internal_require_auth_module())
+            .transfer_operatorship(params, Some(false))
+    }
+
+    /// `set_auth_module_minimum_rotation_delay` lets the owner change the minimum rotation delay of
+    /// the auth module, like `set_minimum_rotation_delay` does while proofs are checked in this
+    /// contract
+    ///
+    /// Arguments:
+    ///
+    /// * `minimum_rotation_delay`: The new delay in nanoseconds, `0` disables it.
+    ///
+    /// Returns:
+    ///
+    /// The promise of the auth module call.
+    #[payable]
+    pub fn set_auth_module_minimum_rotation_delay(
+        &mut self,
+        minimum_rotation_delay: u64,
+    ) -> Promise {
+        self.internal_admin_action("set_auth_module_minimum_rotation_delay");
+
+        ext_auth_module::ext(self.internal_require_auth_module())
+            .set_minimum_rotation_delay(minimum_rotation_delay)
+    }
+
+    // Internal
+
+    /// It panics if a separate auth module checks proofs for the gateway, as the operator sets
+    /// left in this contract are no longer the ones proofs are checked against
+    pub(crate) fn internal_require_local_auth(&self) {
+        if self.auth_module.is_some() {
+            env::panic_str("Operators are managed by the auth module");
+        }
+    }
+
+    /// It panics if proofs are checked in this contract, as there is no auth module to forward
+    /// owner calls to
+    ///
+    /// Returns:
+    ///
+    /// The account id of the auth module.
+    fn internal_require_auth_module(&self) -> AccountId {
+        self.auth_module
+            .clone()
+            .unwrap_or_else(|| env::panic_str("No auth module set"))
+    }
+
+    /// It checks a proof over a hash of signed data, in this contract or through the auth module
+    ///
+    /// Arguments:
+    ///
+    /// * `hash`: The hash of the signed data.
+    /// * `proof`: The encoded proof.
+    ///
+    /// Returns:
+    ///
+    /// Whether the proof is from the current operators, or the pending auth module calls.
+    pub(crate) fn internal_authenticate(&self, hash: [u8; 32], proof: Vec<u8>) -> Authentication {
+        let auth_module = match &self.auth_module {
+            None => {
+                return Authentication::Verified(self.internal_validate_signed_proof(hash, &proof));
+            }
+            Some(auth_module) => auth_module,
+        };
+
+        // One non-panicking call per accepted message hash, resolved together in the callback
+        let promise = self
+            .internal_signed_message_hashes(hash)
+            .into_iter()
+            .map(|message_hash| {
+                ext_auth_module::ext(auth_module.clone())
+                    .inspect_proof(Bytes32(message_hash), Bytes(proof.clone()))
+            })
+            .reduce(|promise, next| promise.and(next))
+            .unwrap();
+
+        Authentication::Pending(promise)
+    }
+
+    /// It resolves the results of the auth module calls of `internal_authenticate`, the same way
+    /// `internal_validate_signed_proof` does
+    ///
+    /// Returns:
+    ///
+    /// The epoch of the operators that signed the proof if they are the current operators of the
+    /// auth module, `None` otherwise.
+    pub(crate) fn internal_resolve_authentication(&self) -> Option<u64> {
+        let mut first_failure = None;
+
+        for i in 0..env::promise_results_count() {
+            let report = match env::promise_result(i) {
+                PromiseResult::Successful(value) => serde_json::from_slice::<ProofReport>(&value)
+                    .unwrap_or_else(|_| env::panic_str("Invalid auth module response")),
+                _ => env::panic_str("Auth module call failed"),
+            };
+
+            match report.failure {
+                None => return report.is_current.then_some(report.epoch),
+                Some(failure) => {
+                    first_failure.get_or_insert(failure);
+                }
+            }
+        }
+
+        env::panic_str(&first_failure.unwrap_or_default())
+    }
+}
@@ -1,17 +1,32 @@
-use crate::events::OperatorshipTransferredEvent;
-use crate::utils::{self, abi_encode, clean_payload, to_h256};
-use crate::{utils::abi_decode, utils::keccak256, Axelar, AxelarExt};
-use ethabi::ethereum_types::{H160, H256};
-use ethabi::{Address, Token};
-use near_contract_tools::owner::*;
+use crate::events::{
+    MinimumRotationDelayUpdatedEvent, OldKeyRetentionUpdatedEvent, OperatorshipTransferredEvent,
+    OperatorshipTransferredEventV2, SigningDomainUpdatedEvent,
+};
+use crate::types::{
+    Bytes, Bytes32, EvmAddress, OperatorKey, Proof, ProofReport, ProofVerifier, SignatureReport,
+    SignersRegistration, SigningDomain, Uint256, WeightedSigners, NEAR_SIGNING_DOMAIN_TAG,
+};
+use crate::utils;
+use crate::{Axelar, AxelarExt};
+use ethabi::ethereum_types::{H256, U256};
+use near_sdk::borsh::BorshSerialize;
 use near_sdk::env;
 
-use ethabi::ParamType;
+use near_contract_tools::owner::*;
 use near_contract_tools::standard::nep297::Event;
 use near_sdk::near_bindgen;
 
-/// A constant that is used to determine how many epochs old keys are valid for.
-pub const OLD_KEY_RETENTION: u8 = 16;
+/// The number of epochs old keys are valid for, unless another value is set at initialization.
+pub const DEFAULT_OLD_KEY_RETENTION: u64 = 16;
+
+/// The chain name of the signing domain, unless another one is set at initialization.
+pub const DEFAULT_CHAIN_NAME: &str = "near";
+
+/// The length of an ed25519 signature, secp256k1 signatures carry an extra recovery byte.
+pub(crate) const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+/// The error of operator set hashes that were never registered.
+const UNKNOWN_HASH: &str = "Unknown operators hash";
 
 /// Axelar Authentication Weighted Implementation
 #[near_bindgen]
@@ -26,7 +41,16 @@ impl Axelar {
     ///
     /// The hash of the block at the given epoch.
     pub fn hash_for_epoch(&self, epoch: u64) -> String {
-        let hash = self.hash_for_epoch.get(&epoch).unwrap();
+        self.internal_require_local_auth();
+
+        if epoch > 0 && epoch <= self.pruned_epoch {
+            env::panic_str(&format!("Epoch {} has been pruned", epoch));
+        }
+
+        let hash = self
+            .hash_for_epoch
+            .get(&epoch)
+            .unwrap_or_else(|| env::panic_str("Unknown epoch"));
         utils::to_eth_hex_string(hash)
     }
 
@@ -39,14 +63,161 @@ impl Axelar {
     /// Returns:
     ///
     /// The epoch for the hash.
-    pub fn epoch_for_hash(&self, hash: String) -> u64 {
-        let hash: [u8; 32] = clean_payload(hash).try_into().unwrap();
-        self.epoch_for_hash.get(&hash).unwrap()
+    pub fn epoch_for_hash(&self, hash: Bytes32) -> u64 {
+        self.internal_require_local_auth();
+
+        self.internal_registered_epoch(hash.0)
+    }
+
+    /// `signers_for_hash` returns the epoch and nonce an operator set hash was registered with
+    ///
+    /// Arguments:
+    ///
+    /// * `hash`: The hash of the operator set.
+    ///
+    /// Returns:
+    ///
+    /// The epoch and nonce of the operator set.
+    pub fn signers_for_hash(&self, hash: Bytes32) -> SignersRegistration {
+        self.internal_require_local_auth();
+
+        let epoch = self.internal_registered_epoch(hash.0);
+
+        SignersRegistration {
+            epoch,
+            nonce: self
+                .signers_for_epoch
+                .get(&epoch)
+                .map(|signers| signers.nonce)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// `current_epoch` returns the epoch of the latest operator set
+    ///
+    /// Returns:
+    ///
+    /// The current epoch, or `0` if no operator set has been registered yet.
+    pub fn current_epoch(&self) -> u64 {
+        self.internal_require_local_auth();
+
+        self.current_epoch
+    }
+
+    /// `current_operators` returns the operator set of the current epoch
+    ///
+    /// Returns:
+    ///
+    /// The operators, weights, threshold and nonce of the current operator set, or `None` if it
+    /// was registered before operator sets were stored, see `operators_for_epoch`.
+    pub fn current_operators(&self) -> Option<WeightedSigners> {
+        self.internal_require_local_auth();
+
+        self.operators_for_epoch(self.current_epoch)
+    }
+
+    /// `operators_for_epoch` returns the operator set registered at a given epoch
+    ///
+    /// Arguments:
+    ///
+    /// * `epoch`: The epoch to get the operator set for.
+    ///
+    /// Returns:
+    ///
+    /// The operators, weights, threshold and nonce of the operator set, or `None` for epochs
+    /// migrated from version 1.0.0, which only stored the hash of the operator set.
+    pub fn operators_for_epoch(&self, epoch: u64) -> Option<WeightedSigners> {
+        self.internal_require_local_auth();
+
+        if epoch == 0 || epoch > self.current_epoch {
+            env::panic_str("Unknown epoch");
+        }
+
+        if epoch <= self.pruned_epoch {
+            env::panic_str(&format!("Epoch {} has been pruned", epoch));
+        }
+
+        self.signers_for_epoch.get(&epoch)
+    }
+
+    /// `old_key_retention` returns how many epochs an operator set stays valid for
+    ///
+    /// Returns:
+    ///
+    /// The current key retention window.
+    pub fn old_key_retention(&self) -> u64 {
+        self.internal_require_local_auth();
+
+        self.old_key_retention
+    }
+
+    /// `last_rotation_timestamp` returns when the operator set was last rotated
+    ///
+    /// Returns:
+    ///
+    /// The block timestamp of the last rotation, in nanoseconds.
+    pub fn last_rotation_timestamp(&self) -> u64 {
+        self.internal_require_local_auth();
+
+        self.last_rotation_timestamp
+    }
+
+    /// `minimum_rotation_delay` returns how long signer-driven rotations have to wait after the
+    /// previous rotation
+    ///
+    /// Returns:
+    ///
+    /// The minimum delay in nanoseconds.
+    pub fn minimum_rotation_delay(&self) -> u64 {
+        self.internal_require_local_auth();
+
+        self.minimum_rotation_delay
+    }
+
+    /// `signing_domain` returns the signing domain proofs are checked in
+    ///
+    /// Returns:
+    ///
+    /// `Legacy`, `Transitional` or `Near`.
+    pub fn signing_domain(&self) -> SigningDomain {
+        self.signing_domain
+    }
+
+    /// `domain_separator` returns the hash that binds proofs in the `Near` signing domain to this
+    /// gateway, `keccak256(borsh(protocol_tag, chain_name, current_account_id))`
+    ///
+    /// Returns:
+    ///
+    /// The domain separator of the gateway.
+    pub fn domain_separator(&self) -> Bytes32 {
+        Bytes32(self.internal_domain_separator())
+    }
+
+    /// `proof_verifier` returns the proof system the gateway checks proofs with
+    ///
+    /// Returns:
+    ///
+    /// `WeightedEcdsa`, or `FixedKey` with its key.
+    pub fn proof_verifier(&self) -> ProofVerifier {
+        self.proof_verifier
+    }
+
+    /// `pruned_epoch` returns the most recent epoch that has been pruned from storage
+    ///
+    /// Returns:
+    ///
+    /// The last pruned epoch, or `0` if nothing has been pruned yet.
+    pub fn pruned_epoch(&self) -> u64 {
+        self.internal_require_local_auth();
+
+        self.pruned_epoch
     }
 
     /// If the epoch of the operators is the same as the current epoch, and the epoch of the operators
     /// is not too old, then validate the signatures
     ///
+    /// The proof is checked by the proof verifier of the gateway, see `proof_verifier`.
+    ///
     /// Arguments:
     ///
     /// * `message_hash`: The hash of the message to be signed.
@@ -55,113 +226,329 @@ impl Axelar {
     /// Returns:
     ///
     /// A boolean value.
-    pub fn validate_proof(&self, message_hash: String, proof: String) -> bool {
-        let expected_output_types = vec![
-            ParamType::Array(Box::new(ParamType::Address)),
-            ParamType::Array(Box::new(ParamType::Uint(256))),
-            ParamType::Uint(256),
-            ParamType::Array(Box::new(ParamType::Bytes)),
-        ];
-
-        let payload = clean_payload(proof.clone());
-
-        let tokens = abi_decode(&payload, &expected_output_types).unwrap();
-
-        let (operators, weights, threshold, signatures) = (
-            tokens[0].clone().into_array().unwrap(),
-            tokens[1].clone().into_array().unwrap(),
-            tokens[2].clone().into_uint().unwrap(),
-            tokens[3].clone().into_array().unwrap(),
-        );
+    pub fn validate_proof(&self, message_hash: Bytes32, proof: Bytes) -> bool {
+        self.internal_require_local_auth();
 
-        let encoded_operators = abi_encode(vec![
-            Token::Array(operators.clone()),
-            Token::Array(weights.clone()),
-            Token::Uint(threshold.clone()),
-        ]);
+        self.proof_verifier
+            .verifier()
+            .verify(self, message_hash.0, &proof.0)
+            .unwrap_or_else(|e| env::panic_str(&e))
+    }
 
-        let operators_hash = keccak256(&encoded_operators);
-        let operators_epoch = self.epoch_for_hash.get(&operators_hash).unwrap();
-        let epoch = self.current_epoch;
+    /// `inspect_proof` checks a proof like `validate_proof` does, but reports the outcome of every
+    /// check instead of panicking, so relayers can find out why a proof would be rejected
+    ///
+    /// The proof is checked by the proof verifier of the gateway, see `proof_verifier`.
+    ///
+    /// Arguments:
+    ///
+    /// * `message_hash`: The hash of the message to be signed.
+    /// * `proof`: The proof that is being inspected.
+    ///
+    /// Returns:
+    ///
+    /// The resolved epoch, the recovered signer of each signature, the accumulated weight against
+    /// the threshold and the first failure reason, if any.
+    pub fn inspect_proof(&self, message_hash: Bytes32, proof: Bytes) -> ProofReport {
+        self.internal_require_local_auth();
+
+        self.proof_verifier
+            .verifier()
+            .inspect(self, message_hash.0, &proof.0)
+    }
+
+    /// `prune_epochs` deletes the operator sets that fell out of the key retention window, oldest
+    /// first, and reclaims their storage. Anyone can call it.
+    ///
+    /// The hash of a pruned operator set keeps pointing at its epoch, so the same operator set
+    /// cannot be registered again with the same nonce.
+    ///
+    /// Arguments:
+    ///
+    /// * `limit`: The maximum number of epochs to prune in this call.
+    ///
+    /// Returns:
+    ///
+    /// The number of epochs that were pruned.
+    pub fn prune_epochs(&mut self, limit: u64) -> u64 {
+        self.internal_require_local_auth();
+
+        let prunable_until = self.current_epoch.saturating_sub(self.old_key_retention);
+        let last_epoch = prunable_until.min(self.pruned_epoch.saturating_add(limit));
 
-        if operators_epoch == 0 || epoch - operators_epoch >= OLD_KEY_RETENTION.into() {
-            env::panic_str("Invalid epoch")
+        if last_epoch <= self.pruned_epoch {
+            return 0;
         }
 
-        self.internal_validate_signatures(
-            to_h256(message_hash),
-            operators
-                .clone()
-                .into_iter()
-                .map(|x| x.into_address().unwrap())
-                .collect(),
-            weights
-                .clone()
-                .into_iter()
-                .map(|x| x.into_uint().unwrap().as_u32())
-                .collect(),
-            threshold.as_u32(),
-            signatures.clone(),
-        );
+        for epoch in (self.pruned_epoch + 1)..=last_epoch {
+            self.hash_for_epoch.remove(&epoch);
+            self.signers_for_epoch.remove(&epoch);
+        }
+
+        let pruned = last_epoch - self.pruned_epoch;
+        self.pruned_epoch = last_epoch;
 
-        operators_epoch == epoch
+        pruned
     }
 
-    /// Only owner
+    // Only owner
 
     /// `transfer_operatorship` is a public function that requires the caller to be the owner, and then
     /// calls the internal function `internal_transfer_operatorship`
     ///
     /// Arguments:
     ///
-    /// * `params`: Vec<u8>
+    /// * `params`: The ABI-encoded operators, weights and threshold, or Borsh-encoded
+    ///   `WeightedSigners`, as `0x`-prefixed hex or Base64.
+    /// * `enforce_rotation_delay`: Whether the minimum rotation delay applies, defaults to `false`.
+    ///
+    /// The owner is not bound by the minimum rotation delay, so it can override it in an emergency.
+    #[payable]
+    pub fn transfer_operatorship(
+        &mut self,
+        params: Bytes,
+        enforce_rotation_delay: Option<bool>,
+    ) -> bool {
+        self.internal_admin_override("transfer_operatorship");
+        self.internal_require_local_auth();
+        self.internal_transfer_operatorship(params.0, enforce_rotation_delay.unwrap_or(false))
+    }
+
+    /// `transfer_operatorship_with_signers` is the JSON-native counterpart of
+    /// `transfer_operatorship`, it encodes the operator set in its canonical form before rotating
+    /// to it
+    ///
+    /// Arguments:
+    ///
+    /// * `new_signers`: The new operators, weights, threshold and optional nonce. Operators may
+    ///   be given in any order, each weight stays with its operator.
+    /// * `enforce_rotation_delay`: Whether the minimum rotation delay applies, defaults to `false`.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    #[payable]
+    pub fn transfer_operatorship_with_signers(
+        &mut self,
+        new_signers: WeightedSigners,
+        enforce_rotation_delay: Option<bool>,
+    ) -> bool {
+        self.internal_admin_override("transfer_operatorship_with_signers");
+        self.internal_require_local_auth();
+
+        let new_signers = new_signers
+            .into_canonical()
+            .unwrap_or_else(|e| env::panic_str(&e));
+
+        self.internal_rotate_signers(new_signers, enforce_rotation_delay.unwrap_or(false))
+    }
+
+    /// `set_minimum_rotation_delay` changes how long signer-driven rotations have to wait after the
+    /// previous rotation, so a compromised operator set cannot push honest sets out of the key
+    /// retention window in quick succession
+    ///
+    /// Arguments:
+    ///
+    /// * `minimum_rotation_delay`: The new delay in nanoseconds, `0` disables it.
     #[payable]
-    pub fn transfer_operatorship(&mut self, params: String) -> bool {
+    pub fn set_minimum_rotation_delay(&mut self, minimum_rotation_delay: u64) {
+        self.internal_admin_action("set_minimum_rotation_delay");
+        self.internal_require_local_auth();
+
+        let event = MinimumRotationDelayUpdatedEvent {
+            previous_delay: self.minimum_rotation_delay,
+            new_delay: minimum_rotation_delay,
+        };
+
+        self.minimum_rotation_delay = minimum_rotation_delay;
+
+        event.emit();
+    }
+
+    /// `set_old_key_retention` changes how many epochs old operator sets can still sign for, so it
+    /// can be shortened without a redeploy, e.g. after a suspected key compromise
+    ///
+    /// The window can only grow once every operator set that fell out of it has been pruned, see
+    /// `prune_epochs`, so expired keys never become valid again.
+    ///
+    /// Arguments:
+    ///
+    /// * `old_key_retention`: The new retention window in epochs, must be greater than zero.
+    #[payable]
+    pub fn set_old_key_retention(&mut self, old_key_retention: u64) {
+        self.internal_admin_action("set_old_key_retention");
+        self.internal_require_local_auth();
+
+        self.internal_set_old_key_retention(old_key_retention)
+            .unwrap_or_else(|e| env::panic_str(&e));
+    }
+
+    /// `transfer_operatorship_from_gateway` rotates to an operator set for a gateway that uses this
+    /// contract as its auth module, once it checked the proof of the current operators
+    ///
+    /// The rotation is rejected if the operators rotated after the gateway checked the proof, so
+    /// two batches signed by the same operators cannot both rotate. The minimum rotation delay
+    /// applies. Only the gateway, the owner of this contract, can call it, and as the operators
+    /// signed the rotation it is not recorded as an admin override.
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The ABI-encoded operators, weights and threshold, or Borsh-encoded
+    ///   `WeightedSigners`, as `0x`-prefixed hex or Base64.
+    /// * `expected_epoch`: The epoch of the operators that signed the rotation.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    #[payable]
+    pub fn transfer_operatorship_from_gateway(
+        &mut self,
+        params: Bytes,
+        expected_epoch: u64,
+    ) -> bool {
         Self::require_owner();
-        self.internal_transfer_operatorship(clean_payload(params))
+        self.internal_require_local_auth();
+        self.internal_require_current_epoch(expected_epoch);
+        self.internal_transfer_operatorship(params.0, true)
     }
 
-    /// Internal
+    /// `set_old_key_retention_from_gateway` changes the key retention window for a gateway that uses
+    /// this contract as its auth module, once it checked the proof of the current operators
+    ///
+    /// Like `transfer_operatorship_from_gateway`, it is rejected if the operators rotated after
+    /// the gateway checked the proof, and it is not recorded as an admin action.
+    ///
+    /// Arguments:
+    ///
+    /// * `old_key_retention`: The new retention window in epochs, must be greater than zero.
+    /// * `expected_epoch`: The epoch of the operators that signed the change.
+    #[payable]
+    pub fn set_old_key_retention_from_gateway(
+        &mut self,
+        old_key_retention: u64,
+        expected_epoch: u64,
+    ) {
+        Self::require_owner();
+        self.internal_require_local_auth();
+        self.internal_require_current_epoch(expected_epoch);
 
-    /// It takes in a list of addresses and a list of weights, and if the list of addresses is sorted
-    /// and contains no duplicates, and if the list of weights is the same length as the list of
-    /// addresses, and if the sum of the weights is greater than the threshold, then it emits an event
+        self.internal_set_old_key_retention(old_key_retention)
+            .unwrap_or_else(|e| env::panic_str(&e));
+    }
+
+    /// `set_signing_domain` switches the signing domain proofs are checked in. Moving from `Legacy`
+    /// through `Transitional` to `Near` lets relayers migrate without downtime.
     ///
     /// Arguments:
     ///
-    /// * `params`: The parameters passed to the function.
-    pub(crate) fn internal_transfer_operatorship(&mut self, params: Vec<u8>) -> bool {
-        let expected_output_types = vec![
-            ParamType::Array(Box::new(ParamType::Address)),
-            ParamType::Array(Box::new(ParamType::Uint(256))),
-            ParamType::Uint(256),
-        ];
-
-        let tokens = abi_decode(&params, &expected_output_types).unwrap();
-
-        let new_operators = tokens[0]
-            .clone()
-            .into_array()
-            .unwrap()
-            .into_iter()
-            .map(|token| token.into_address().unwrap())
-            .collect::<Vec<_>>();
+    /// * `signing_domain`: The new signing domain.
+    #[payable]
+    pub fn set_signing_domain(&mut self, signing_domain: SigningDomain) {
+        self.internal_admin_action("set_signing_domain");
 
-        let new_weights = tokens[1]
-            .clone()
-            .into_array()
-            .unwrap()
-            .into_iter()
-            .map(|token| token.into_uint().unwrap())
-            .collect::<Vec<_>>();
+        let event = SigningDomainUpdatedEvent {
+            previous_signing_domain: format!("{:?}", self.signing_domain),
+            new_signing_domain: format!("{:?}", signing_domain),
+        };
 
-        let new_threshold = tokens[2].clone().into_uint().unwrap();
+        self.signing_domain = signing_domain;
+
+        event.emit();
+    }
+
+    // Internal
+
+    /// It panics if the operators rotated after the epoch a command forwarded by the gateway was
+    /// signed at
+    ///
+    /// Arguments:
+    ///
+    /// * `expected_epoch`: The epoch of the operators that signed the command.
+    fn internal_require_current_epoch(&self, expected_epoch: u64) {
+        if self.current_epoch != expected_epoch {
+            env::panic_str("Not latest signers");
+        }
+    }
+
+    /// It changes the key retention window and emits an event, unless the window is empty or
+    /// would bring back operator sets that expired but were not pruned yet
+    ///
+    /// Arguments:
+    ///
+    /// * `old_key_retention`: The new number of epochs an operator set stays valid for.
+    ///
+    /// Returns:
+    ///
+    /// The reason the key retention window was not changed, if any.
+    pub(crate) fn internal_set_old_key_retention(
+        &mut self,
+        old_key_retention: u64,
+    ) -> Result<(), String> {
+        if old_key_retention == 0 {
+            return Err("Invalid key retention".to_string());
+        }
+
+        let expired_epoch = self.current_epoch.saturating_sub(self.old_key_retention);
+
+        if old_key_retention > self.old_key_retention && expired_epoch > self.pruned_epoch {
+            return Err("Expired epochs not pruned".to_string());
+        }
+
+        let event = OldKeyRetentionUpdatedEvent {
+            previous_retention: self.old_key_retention,
+            new_retention: old_key_retention,
+        };
+
+        self.old_key_retention = old_key_retention;
+
+        event.emit();
+
+        Ok(())
+    }
+
+    /// It decodes the ABI or Borsh-encoded operator set and rotates to it
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The parameters passed to the function.
+    /// * `enforce_rotation_delay`: Whether the minimum rotation delay applies.
+    pub(crate) fn internal_transfer_operatorship(
+        &mut self,
+        params: Vec<u8>,
+        enforce_rotation_delay: bool,
+    ) -> bool {
+        let new_signers = WeightedSigners::decode(&params).unwrap_or_else(|e| env::panic_str(&e));
+
+        self.internal_rotate_signers(new_signers, enforce_rotation_delay)
+    }
+
+    /// It takes in a list of addresses and a list of weights, and if the list of addresses is sorted
+    /// and contains no duplicates, and if the list of weights is the same length as the list of
+    /// addresses, and if the sum of the weights is greater than the threshold, then it starts a new
+    /// epoch for the operator set and emits an event
+    ///
+    /// Arguments:
+    ///
+    /// * `new_signers`: The new operator set.
+    /// * `enforce_rotation_delay`: Whether the minimum rotation delay applies. Only rotations
+    ///   driven by operator signatures enforce it.
+    pub(crate) fn internal_rotate_signers(
+        &mut self,
+        new_signers: WeightedSigners,
+        enforce_rotation_delay: bool,
+    ) -> bool {
+        let WeightedSigners {
+            operators: new_operators,
+            weights: new_weights,
+            threshold: new_threshold,
+            nonce: new_nonce,
+        } = &new_signers;
 
         let operators_length = new_operators.len();
         let weights_length = new_weights.len();
 
         if operators_length == 0
-            || !Axelar::internal_is_sorted_asc_and_contains_no_duplicate(new_operators.clone())
+            || !Axelar::internal_is_sorted_asc_and_contains_no_duplicate(new_operators)
         {
             env::panic_str("Invalid operators");
         }
@@ -170,17 +557,19 @@ impl Axelar {
             env::panic_str("Invalid weights");
         }
 
-        let mut total_weight: u32 = 0;
+        let mut total_weight = U256::zero();
 
-        for i in 0..weights_length {
-            total_weight += new_weights[i].low_u32();
+        for weight in new_weights {
+            total_weight = total_weight
+                .checked_add(weight.0)
+                .unwrap_or_else(|| env::panic_str("Invalid weights"));
         }
 
-        if new_threshold.low_u32() == 0 || total_weight < new_threshold.low_u32() {
+        if new_threshold.0.is_zero() || total_weight < new_threshold.0 {
             env::panic_str("Invalid threshold");
         }
 
-        let new_operators_hash = keccak256(params);
+        let new_operators_hash = new_signers.hash();
 
         let existing_epoch = self.epoch_for_hash.get(&new_operators_hash).unwrap_or(0);
 
@@ -188,81 +577,284 @@ impl Axelar {
             env::panic_str("Duplicate operators");
         }
 
+        let timestamp = env::block_timestamp();
+
+        if enforce_rotation_delay
+            && timestamp.saturating_sub(self.last_rotation_timestamp) < self.minimum_rotation_delay
+        {
+            env::panic_str("Insufficient rotation delay");
+        }
+
         let epoch = self.current_epoch + 1;
         self.current_epoch = epoch;
+        self.last_rotation_timestamp = timestamp;
         self.hash_for_epoch.insert(&epoch, &new_operators_hash);
         self.epoch_for_hash.insert(&new_operators_hash, &epoch);
+        self.signers_for_epoch.insert(&epoch, &new_signers);
+
+        let operators = new_operators
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
 
-        // Emit event
-        let event = OperatorshipTransferredEvent {
-            new_operators: new_operators
-                .iter()
-                .map(|x| format!("{:#x}", x))
-                .collect::<Vec<_>>(),
-            new_weights: new_weights.iter().map(|x| x.as_u64()).collect::<Vec<_>>(),
-            new_threshold: new_threshold.as_u64(),
+        // Emit event, version 1.0.0 reports weights as u64s and is left out if they do not fit
+        let fits = |x: &Uint256| x.0.bits() <= 64;
+
+        if new_weights.iter().all(fits) && fits(new_threshold) {
+            OperatorshipTransferredEvent {
+                new_operators: operators.clone(),
+                new_weights: new_weights.iter().map(|x| x.0.as_u64()).collect(),
+                new_threshold: new_threshold.0.as_u64(),
+            }
+            .emit();
+        }
+
+        let event_v2 = OperatorshipTransferredEventV2 {
+            epoch,
+            operators_hash: utils::to_eth_hex_string(new_operators_hash),
+            new_operators: operators,
+            new_weights: new_weights.iter().map(|x| x.to_string()).collect(),
+            new_threshold: new_threshold.to_string(),
+            new_nonce: new_nonce.to_string(),
+            timestamp,
         };
 
-        event.emit();
+        event_v2.emit();
 
         true
     }
 
-    /// It takes a list of operators, a list of weights, a threshold, and a list of signatures, and it
-    /// checks that the signatures are valid
+    /// It validates a proof over a hash of signed data in the signing domain of the gateway. In the
+    /// `Transitional` domain a proof in either domain is accepted, the `Near` one is reported on
+    /// failure.
     ///
     /// Arguments:
     ///
-    /// * `message_hash`: The hash of the message to be signed.
-    /// * `operators`: The list of operators that are allowed to sign the transaction.
-    /// * `weights`: The weight of each operator.
-    /// * `threshold`: The minimum number of signatures required to validate the transaction.
-    /// * `signatures`: A list of signatures.
-    fn internal_validate_signatures(
+    /// * `hash`: The hash of the signed data.
+    /// * `proof`: The encoded proof, checked by the proof verifier of the gateway.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the proof was signed by the current operators.
+    pub(crate) fn internal_validate_signed_proof(&self, hash: [u8; 32], proof: &[u8]) -> bool {
+        let verifier = self.proof_verifier.verifier();
+        let mut first_failure = None;
+
+        for message_hash in self.internal_signed_message_hashes(hash) {
+            match verifier.verify(self, message_hash, proof) {
+                Ok(is_current) => return is_current,
+                Err(failure) => {
+                    first_failure.get_or_insert(failure);
+                }
+            }
+        }
+
+        env::panic_str(&first_failure.unwrap_or_default())
+    }
+
+    /// It returns the message hashes a proof over a hash of signed data is accepted for, in the
+    /// order they are tried
+    ///
+    /// Arguments:
+    ///
+    /// * `hash`: The hash of the signed data.
+    ///
+    /// Returns:
+    ///
+    /// The `Near` and/or `Legacy` message hash, depending on the signing domain.
+    pub(crate) fn internal_signed_message_hashes(&self, hash: [u8; 32]) -> Vec<[u8; 32]> {
+        let legacy_message_hash = || utils::to_eth_signed_message_hash(hash);
+        let near_message_hash =
+            || utils::to_domain_signed_message_hash(self.internal_domain_separator(), hash);
+
+        match self.signing_domain {
+            SigningDomain::Legacy => vec![legacy_message_hash()],
+            SigningDomain::Near => vec![near_message_hash()],
+            SigningDomain::Transitional => vec![near_message_hash(), legacy_message_hash()],
+        }
+    }
+
+    /// It returns the epoch an operator set hash was registered at, unless it has been pruned
+    ///
+    /// Arguments:
+    ///
+    /// * `hash`: The hash of the operator set.
+    ///
+    /// Returns:
+    ///
+    /// The epoch of the operator set.
+    fn internal_registered_epoch(&self, hash: [u8; 32]) -> u64 {
+        let epoch = self
+            .epoch_for_hash
+            .get(&hash)
+            .unwrap_or_else(|| env::panic_str(UNKNOWN_HASH));
+
+        if epoch <= self.pruned_epoch {
+            env::panic_str(&format!("Epoch {} has been pruned", epoch));
+        }
+
+        epoch
+    }
+
+    /// It hashes the Borsh encoding of the protocol tag, the chain name and the gateway account id
+    ///
+    /// Returns:
+    ///
+    /// A 32 byte array
+    fn internal_domain_separator(&self) -> [u8; 32] {
+        let domain = (
+            NEAR_SIGNING_DOMAIN_TAG.to_string(),
+            self.chain_name.clone(),
+            env::current_account_id().to_string(),
+        );
+
+        utils::keccak256(domain.try_to_vec().unwrap())
+    }
+
+    /// It resolves the epoch of the proof's operator set, rejects it if it is older than the key
+    /// retention, and then checks the signatures, recording the outcome of each check instead of
+    /// panicking
+    ///
+    /// Arguments:
+    ///
+    /// * `message_hash`: The hash of the message that was signed.
+    /// * `proof`: The decoded proof.
+    ///
+    /// Returns:
+    ///
+    /// A report with the first failure, if any.
+    pub(crate) fn internal_inspect_proof(
         &self,
-        message_hash: H256,
-        operators: Vec<Address>,
-        weights: Vec<u32>,
-        threshold: u32,
-        signatures: Vec<Token>,
-    ) {
-        let operator_length = operators.len();
-        let mut operator_index = 0;
-        let mut weight = 0;
+        message_hash: [u8; 32],
+        proof: &Proof,
+    ) -> ProofReport {
+        let WeightedSigners {
+            operators,
+            weights,
+            threshold,
+            ..
+        } = &proof.signers;
+
+        let operators_epoch = self.epoch_for_hash.get(&proof.signers.hash()).unwrap_or(0);
+        let epoch = self.current_epoch;
 
-        for i in 0..signatures.len() {
-            let signature: &[u8] = &signatures[i].clone().into_bytes().unwrap();
+        let mut failure = None;
 
-            let signer = utils::ecrecover(message_hash, signature).unwrap();
+        if operators_epoch <= self.pruned_epoch || epoch - operators_epoch >= self.old_key_retention
+        {
+            failure = Some("Invalid epoch".to_string());
+        }
 
-            while operator_index < operator_length && operators[operator_index] != signer {
-                operator_index += 1;
-            }
+        let has_ed25519_operators = operators
+            .iter()
+            .any(|operator| matches!(operator, OperatorKey::Ed25519(_)));
 
-            if operator_index >= operator_length {
-                env::panic_str(
-                    format!(
-                        "Malformed signers. Operators {}",
-                        operators
-                            .iter()
-                            .map(|x| format!("\"{}\"", x))
-                            .collect::<Vec<_>>()
-                            .join(",")
-                    )
-                    .as_str(),
-                );
+        let mut signatures = Vec::with_capacity(proof.signatures.len());
+        let mut operator_index = 0;
+        let mut weight = U256::zero();
+        let mut threshold_met = false;
+
+        for signature in &proof.signatures {
+            // ed25519 signatures cannot be recovered, they are verified against the operators
+            let recovered = if has_ed25519_operators && signature.len() == ED25519_SIGNATURE_LENGTH
+            {
+                Ok(None)
+            } else {
+                utils::ecrecover(H256(message_hash), signature)
+                    .map(|x| Some(OperatorKey::Secp256k1(EvmAddress(x.0))))
+            };
+
+            let mut report = SignatureReport {
+                signer: recovered.ok().flatten(),
+                operator_index: None,
+                weight: None,
+            };
+
+            // Signatures past the threshold or the first failure are only recovered
+            if failure.is_none() && !threshold_met {
+                match recovered {
+                    Err(e) => failure = Some(e.to_string()),
+                    Ok(signer) => {
+                        while operator_index < operators.len()
+                            && !Self::internal_is_signed_by(
+                                &operators[operator_index],
+                                signer,
+                                &message_hash,
+                                signature,
+                            )
+                        {
+                            operator_index += 1;
+                        }
+
+                        if operator_index >= operators.len() {
+                            failure = Some(format!(
+                                "Malformed signers. Operators {}",
+                                operators
+                                    .iter()
+                                    .map(|x| format!("\"{}\"", x))
+                                    .collect::<Vec<_>>()
+                                    .join(",")
+                            ));
+                        } else {
+                            let operator_weight =
+                                weights.get(operator_index).copied().unwrap_or_default();
+
+                            report.signer = Some(operators[operator_index]);
+                            report.operator_index = Some(operator_index as u64);
+                            report.weight = Some(operator_weight);
+
+                            weight = weight.saturating_add(operator_weight.0);
+                            threshold_met = weight >= threshold.0;
+                            operator_index += 1;
+                        }
+                    }
+                }
             }
 
-            weight += weights[operator_index];
+            signatures.push(report);
+        }
 
-            if weight >= threshold {
-                return;
-            }
+        if failure.is_none() && !threshold_met {
+            failure = Some("Low signature weight".to_string());
+        }
 
-            operator_index += 1;
+        ProofReport {
+            epoch: operators_epoch,
+            is_current: operators_epoch != 0 && operators_epoch == epoch,
+            signatures,
+            accumulated_weight: Uint256(weight),
+            threshold: *threshold,
+            failure,
         }
+    }
 
-        env::panic_str("Low signature weight");
+    /// It checks whether a signature belongs to an operator, comparing the recovered signer for
+    /// secp256k1 operators and verifying the signature for ed25519 operators
+    ///
+    /// Arguments:
+    ///
+    /// * `operator`: The operator to check.
+    /// * `signer`: The signer recovered from the signature, or `None` for an ed25519 signature.
+    /// * `message_hash`: The hash of the message that was signed.
+    /// * `signature`: The signature.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    fn internal_is_signed_by(
+        operator: &OperatorKey,
+        signer: Option<OperatorKey>,
+        message_hash: &[u8; 32],
+        signature: &[u8],
+    ) -> bool {
+        match (operator, signer) {
+            (_, Some(signer)) => *operator == signer,
+            (OperatorKey::Ed25519(public_key), None) => signature
+                .try_into()
+                .map(|signature| utils::ed25519_verify(signature, message_hash, public_key))
+                .unwrap_or(false),
+            (OperatorKey::Secp256k1(_), None) => false,
+        }
     }
 
     /// > This function checks if the given vector of accounts is sorted in ascending order and contains
@@ -270,18 +862,18 @@ impl Axelar {
     ///
     /// Arguments:
     ///
-    /// * `accounts`: A vector of H160, which is a type of vector of 20 bytes.
+    /// * `accounts`: A slice of operator keys.
     ///
     /// Returns:
     ///
     /// A boolean value.
-    fn internal_is_sorted_asc_and_contains_no_duplicate(accounts: Vec<H160>) -> bool {
+    fn internal_is_sorted_asc_and_contains_no_duplicate(accounts: &[OperatorKey]) -> bool {
         for i in 0..(accounts.len() - 1) {
             if accounts[i] >= accounts[i + 1] {
                 return false;
             }
         }
 
-        return !accounts[0].is_zero();
+        !accounts.iter().any(OperatorKey::is_zero)
     }
 }
@@ -1,6 +1,6 @@
 use near_contract_tools::event;
 
-/// Auth Events
+// Auth Events
 
 /// `OperatorshipTransferredEvent` is emitted when the operatorship is transferred.
 ///
@@ -9,6 +9,9 @@ use near_contract_tools::event;
 /// * `new_operators`: The new list of operators.
 /// * `new_weights`: A comma-separated list of weights for the new operators.
 /// * `new_threshold`: The new threshold for the operatorship.
+///
+/// It is not emitted for operator sets with a weight or threshold above `u64::MAX`,
+/// `OperatorshipTransferredEventV2` carries them in full.
 #[event(standard = "axelar_near", version = "1.0.0")]
 pub struct OperatorshipTransferredEvent {
     pub new_operators: Vec<String>,
@@ -16,7 +19,82 @@ pub struct OperatorshipTransferredEvent {
     pub new_threshold: u64,
 }
 
-/// Gateway Events
+/// `OldKeyRetentionUpdatedEvent` is emitted when the key retention window is changed.
+///
+/// Properties:
+///
+/// * `previous_retention`: The previous number of epochs old keys were valid for.
+/// * `new_retention`: The new number of epochs old keys are valid for.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct OldKeyRetentionUpdatedEvent {
+    pub previous_retention: u64,
+    pub new_retention: u64,
+}
+
+/// `SigningDomainUpdatedEvent` is emitted when the signing domain is switched.
+///
+/// Properties:
+///
+/// * `previous_signing_domain`: The signing domain proofs were checked in.
+/// * `new_signing_domain`: The signing domain proofs are checked in from now on.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct SigningDomainUpdatedEvent {
+    pub previous_signing_domain: String,
+    pub new_signing_domain: String,
+}
+
+/// `MinimumRotationDelayUpdatedEvent` is emitted when the minimum rotation delay is changed.
+///
+/// Properties:
+///
+/// * `previous_delay`: The previous minimum delay between rotations, in nanoseconds.
+/// * `new_delay`: The new minimum delay between rotations, in nanoseconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct MinimumRotationDelayUpdatedEvent {
+    pub previous_delay: u64,
+    pub new_delay: u64,
+}
+
+/// `OperatorshipTransferredEventV2` is version 2.0.0 of `OperatorshipTransferredEvent`, emitted next
+/// to it, that also identifies the new operator set and carries its full-precision weights.
+///
+/// Properties:
+///
+/// * `epoch`: The epoch the new operator set was registered at.
+/// * `operators_hash`: The hash the new operator set was registered under.
+/// * `new_operators`: The new list of operators.
+/// * `new_weights`: The weights of the new operators, as decimal strings.
+/// * `new_threshold`: The new threshold for the operatorship, as a decimal string.
+/// * `new_nonce`: The nonce the new operator set is hashed with.
+/// * `timestamp`: The block timestamp of the rotation, in nanoseconds.
+#[event(
+    standard = "axelar_near",
+    version = "2.0.0",
+    name = "operatorship_transferred_event"
+)]
+pub struct OperatorshipTransferredEventV2 {
+    pub epoch: u64,
+    pub operators_hash: String,
+    pub new_operators: Vec<String>,
+    pub new_weights: Vec<String>,
+    pub new_threshold: String,
+    pub new_nonce: String,
+    pub timestamp: u64,
+}
+
+/// `AuthModuleUpdatedEvent` is emitted when the gateway switches its auth module.
+///
+/// Properties:
+///
+/// * `previous_auth_module`: The account id of the previous auth module.
+/// * `new_auth_module`: The account id of the new auth module.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct AuthModuleUpdatedEvent {
+    pub previous_auth_module: String,
+    pub new_auth_module: String,
+}
+
+// Gateway Events
 
 /// `ContractCallEvent` is emitted when a contract call is made to the gateway.
 ///
@@ -46,6 +124,19 @@ pub struct ExecutedEvent {
     pub command_id: String,
 }
 
+/// `ExecutedEventV2` is version 2.0.0 of `ExecutedEvent`, emitted next to it, that also names the
+/// command.
+///
+/// Properties:
+///
+/// * `command_id`: The command ID that was executed.
+/// * `selector`: The selector of the command, e.g. `approveContractCall`.
+#[event(standard = "axelar_near", version = "2.0.0", name = "executed_event")]
+pub struct ExecutedEventV2 {
+    pub command_id: String,
+    pub selector: String,
+}
+
 /// `ContractCallApprovedEvent` is emitted when a contract call is approved.
 ///
 /// Properties:
@@ -67,3 +158,180 @@ pub struct ContractCallApprovedEvent {
     pub source_tx_hash: String,
     pub source_event_index: u64,
 }
+
+/// `ContractCallApprovedEventV2` is version 2.0.0 of `ContractCallApprovedEvent`, emitted next to
+/// it, that also records when the call was approved.
+///
+/// Properties:
+///
+/// * `command_id`: The command ID of the command that was approved.
+/// * `source_chain`: The chain that the contract call originated from.
+/// * `source_address`: The address of the contract that is calling the target contract.
+/// * `contract_address`: The address of the contract that was called.
+/// * `payload_hash`: The hash of the payload that was sent to the contract.
+/// * `source_tx_hash`: The hash of the transaction that triggered the event.
+/// * `source_event_index`: The index of the event in the source chain.
+/// * `approval_block_height`: The NEAR block height the contract call was approved at.
+#[event(
+    standard = "axelar_near",
+    version = "2.0.0",
+    name = "contract_call_approved_event"
+)]
+pub struct ContractCallApprovedEventV2 {
+    pub command_id: String,
+    pub source_chain: String,
+    pub source_address: String,
+    pub contract_address: String,
+    pub payload_hash: String,
+    pub source_tx_hash: String,
+    pub source_event_index: u64,
+    pub approval_block_height: u64,
+}
+
+/// `MessageApprovedEvent` is emitted when a message is approved through `approve_messages`.
+///
+/// Properties:
+///
+/// * `command_id`: The command ID the message is tracked under.
+/// * `source_chain`: The chain that the message originated from.
+/// * `message_id`: The id of the message on the source chain.
+/// * `source_address`: The address of the sender on the source chain.
+/// * `contract_address`: The NEAR account the message is sent to.
+/// * `payload_hash`: The hash of the message payload.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct MessageApprovedEvent {
+    pub command_id: String,
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub contract_address: String,
+    pub payload_hash: String,
+}
+
+/// `MessageExecutedEvent` is emitted when an approved message is validated by its destination
+/// contract.
+///
+/// Properties:
+///
+/// * `command_id`: The command ID the message is tracked under.
+/// * `source_chain`: The chain that the message originated from.
+/// * `message_id`: The id of the message on the source chain.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct MessageExecutedEvent {
+    pub command_id: String,
+    pub source_chain: String,
+    pub message_id: String,
+}
+
+// Ownership Events
+
+/// `OwnershipTransferProposedEvent` is emitted when the owner proposes a new owner.
+///
+/// Properties:
+///
+/// * `owner`: The current owner.
+/// * `proposed_owner`: The account that can accept ownership.
+/// * `expires_at`: The block timestamp the proposal expires at, in nanoseconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct OwnershipTransferProposedEvent {
+    pub owner: String,
+    pub proposed_owner: String,
+    pub expires_at: u64,
+}
+
+/// `OwnershipTransferCancelledEvent` is emitted when the owner cancels a proposed transfer.
+///
+/// Properties:
+///
+/// * `owner`: The current owner.
+/// * `proposed_owner`: The account that could have accepted ownership.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct OwnershipTransferCancelledEvent {
+    pub owner: String,
+    pub proposed_owner: String,
+}
+
+/// `OwnershipTransferredEvent` is emitted when ownership changes hands, when a proposed owner
+/// accepts or through a `transferOwnership` command.
+///
+/// Properties:
+///
+/// * `previous_owner`: The previous owner.
+/// * `new_owner`: The new owner.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct OwnershipTransferredEvent {
+    pub previous_owner: String,
+    pub new_owner: String,
+}
+
+// Admin Events
+
+/// `AdminActionEvent` is emitted next to the events of every call the owner makes, so manual
+/// overrides can be told apart from actions of the operators.
+///
+/// Properties:
+///
+/// * `method`: The method the owner called.
+/// * `caller`: The account that called it.
+/// * `args_hash`: The keccak256 hash of the JSON arguments of the call.
+/// * `count`: The number of admin actions so far, including this one.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct AdminActionEvent {
+    pub method: String,
+    pub caller: String,
+    pub args_hash: String,
+    pub count: u64,
+}
+
+/// `AdminOverrideEvent` is emitted next to the `AdminActionEvent` of an owner call that approves a
+/// contract call or rotates the operators, which the operators do otherwise.
+///
+/// Properties:
+///
+/// * `method`: The method the owner called.
+/// * `caller`: The account that called it.
+/// * `count`: The number of admin overrides so far, including this one.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct AdminOverrideEvent {
+    pub method: String,
+    pub caller: String,
+    pub count: u64,
+}
+
+/// `AdminApprovalsDisabledEvent` is emitted when the owner permanently gives up approving contract
+/// calls.
+///
+/// Properties:
+///
+/// * `caller`: The owner that disabled admin approvals.
+/// * `command_ids`: The command IDs of earlier owner approvals that were marked as executed.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct AdminApprovalsDisabledEvent {
+    pub caller: String,
+    pub command_ids: Vec<String>,
+}
+
+/// `UpgradeStagedEvent` is emitted when contract code is staged for an `upgrade` command.
+///
+/// Properties:
+///
+/// * `code_hash`: The sha256 hash of the staged code.
+/// * `size`: The size of the staged code, in bytes.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct UpgradeStagedEvent {
+    pub code_hash: String,
+    pub size: u64,
+}
+
+/// `UpgradedEvent` is emitted when an `upgrade` command deploys staged code.
+///
+/// Properties:
+///
+/// * `code_hash`: The sha256 hash of the deployed code.
+/// * `has_migration_args`: Whether the command carried migration arguments. `migrate` is called
+///   either way, with `{}` if there are none.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct UpgradedEvent {
+    pub code_hash: String,
+    pub has_migration_args: bool,
+}
@@ -1,15 +1,24 @@
-use crate::events::{ContractCallApprovedEvent, ContractCallEvent, ExecutedEvent};
-use crate::utils::{self, abi_encode, clean_payload};
-use crate::{utils::abi_decode, utils::keccak256, Axelar, AxelarExt};
+use crate::admin::ADMIN_SELECTORS;
+use crate::auth_module::{ext_auth_module, Authentication};
+use crate::events::{
+    AdminApprovalsDisabledEvent, ContractCallApprovedEvent, ContractCallApprovedEventV2,
+    ContractCallEvent, ExecutedEvent, ExecutedEventV2,
+};
+use crate::types::{
+    BatchEncoding, Bytes, Bytes32, CommandBatch, ContractCallParams, ExecuteInput, WeightedSigners,
+    BORSH_ENCODING_VERSION,
+};
+use crate::utils::{self, abi_encode};
+use crate::{utils::keccak256, Axelar, AxelarExt};
 use ethabi::Token;
 use near_contract_tools::owner::*;
+use near_contract_tools::pause::*;
 use near_sdk::env::{self};
 
-use ethabi::ParamType;
 use near_contract_tools::standard::nep297::Event;
+use near_sdk::borsh::{self, BorshSerialize};
 use near_sdk::env::predecessor_account_id;
-use near_sdk::{near_bindgen, AccountId};
-use uint::hex::{self};
+use near_sdk::{near_bindgen, serde_json, Promise, PromiseOrValue, PromiseResult};
 
 /// Defining a constant string called SELECTOR_APPROVE_CONTRACT_CALL.
 pub const SELECTOR_APPROVE_CONTRACT_CALL: &str = "approveContractCall";
@@ -26,21 +35,23 @@ impl Axelar {
     ///
     /// * `destination_chain`: The chain that the contract is on.
     /// * `destination_contract_address`: The address of the contract you want to call.
-    /// * `payload`: The payload to be sent to the destination contract.  
+    /// * `payload`: The payload to be sent to the destination contract, as `0x`-prefixed hex or Base64.
     #[payable]
     pub fn call_contract(
         destination_chain: String,
         destination_contract_address: String,
-        payload: String,
+        payload: Bytes,
     ) -> ContractCallEvent {
-        let payload_hash = keccak256(clean_payload(payload.clone()));
+        Self::require_unpaused();
+
+        let payload_hash = keccak256(&payload.0);
 
         let event = ContractCallEvent {
             address: predecessor_account_id().to_string(),
             destination_chain,
             destination_contract_address,
-            payload_hash: utils::to_eth_hex_string(payload_hash.try_into().unwrap()),
-            payload,
+            payload_hash: utils::to_eth_hex_string(payload_hash),
+            payload: payload.to_string(),
         };
 
         Event::emit(&event);
@@ -50,157 +61,213 @@ impl Axelar {
 
     // Execute command function
 
-    /// It takes a message hash and a proof, validates the proof, and then executes the commands in the
-    /// message
+    /// It takes a signed command batch, validates the proof, and then executes the commands in the
+    /// batch
+    ///
+    /// The input is either the ABI encoding of `(bytes data, bytes proof)`, or the
+    /// `BORSH_ENCODING_VERSION` byte followed by a Borsh-encoded `ExecuteInput`. Borsh batches are
+    /// signed over the hash of `BORSH_BATCH_DOMAIN_TAG` and the batch, ABI batches over the hash
+    /// of the batch alone.
+    ///
+    /// Only the owner can execute batches that approve contract calls which are not executed yet.
     ///
     /// Arguments:
     ///
-    /// * `message_hash`: The hash of the message that was signed by the operator.
-    /// * `input`: The input to the contract. This is the data that is passed to the contract.
+    /// * `input`: The encoded input, as `0x`-prefixed hex or Base64.
     ///
     /// Returns:
     ///
     /// The return value is a vector of booleans. Each boolean represents the result of the execution of
-    /// a command.
+    /// a command. With a separate auth module, it is returned by `on_execute_authenticated`.
     #[payable]
-    pub fn execute(&mut self, input: String) -> Vec<bool> {
-        let payload = clean_payload(input.clone());
-
-        let tokens = abi_decode(&payload, &vec![ParamType::Bytes, ParamType::Bytes]).unwrap();
+    pub fn execute(&mut self, input: Bytes) -> PromiseOrValue<Vec<bool>> {
+        let (batch, batch_hash, proof) =
+            ExecuteInput::decode(&input.0).unwrap_or_else(|e| env::panic_str(&e));
+
+        // Checked before authenticating, with an auth module the approvals run in a callback
+        if batch
+            .commands
+            .iter()
+            .zip(&batch.command_ids)
+            .any(|(command, command_id)| {
+                command == SELECTOR_APPROVE_CONTRACT_CALL
+                    && !self.is_command_executed(Bytes32(*command_id))
+            })
+        {
+            Self::require_owner();
+        }
 
-        let data = tokens[0].clone().into_bytes().unwrap();
-        let proof = tokens[1].clone().into_bytes().unwrap();
+        match self.internal_authenticate(batch_hash, proof) {
+            Authentication::Verified(is_current) => {
+                self.internal_execute(batch, is_current.then_some(self.current_epoch))
+            }
+            Authentication::Pending(promise) => PromiseOrValue::Promise(
+                promise.then(Self::ext(env::current_account_id()).on_execute_authenticated(batch)),
+            ),
+        }
+    }
 
-        let message = keccak256(data.clone());
-        const PREFIX: &str = "\x19Ethereum Signed Message:\n32";
-        let mut eth_message = PREFIX.as_bytes().to_vec();
-        eth_message.extend_from_slice(message.as_ref());
+    // Callbacks
 
-        let hash_message = format!("0x{}", hex::encode(keccak256(eth_message)));
+    /// It executes a command batch once the auth module checked its proof
+    ///
+    /// Arguments:
+    ///
+    /// * `batch`: The Borsh-encoded command batch.
+    ///
+    /// Returns:
+    ///
+    /// The result of the execution of each command.
+    #[private]
+    pub fn on_execute_authenticated(
+        &mut self,
+        #[serializer(borsh)] batch: CommandBatch,
+    ) -> PromiseOrValue<Vec<bool>> {
+        let signers_epoch = self.internal_resolve_authentication();
 
-        let mut allow_operatorship_transfer =
-            self.validate_proof(hash_message, format!("0x{}", hex::encode(proof)));
+        self.internal_execute(batch, signers_epoch)
+    }
 
-        let expected_output_types = vec![
-            ParamType::Uint(256),
-            ParamType::Array(Box::new(ParamType::FixedBytes(32))),
-            ParamType::Array(Box::new(ParamType::String)),
-            ParamType::Array(Box::new(ParamType::Bytes)),
-        ];
+    /// It marks a command forwarded to the auth module as executed once the auth module
+    /// accepted it
+    ///
+    /// Arguments:
+    ///
+    /// * `command_id`: The ID of the command.
+    /// * `selector`: The selector of the command.
+    ///
+    /// Returns:
+    ///
+    /// Whether the command was executed.
+    #[private]
+    pub fn on_auth_module_command(&mut self, command_id: Bytes32, selector: String) -> bool {
+        if !matches!(env::promise_result(0), PromiseResult::Successful(_))
+            || self.is_command_executed(command_id)
+        {
+            return false;
+        }
 
-        let data_tokens = abi_decode(&data, &expected_output_types).unwrap();
+        self.internal_set_command_executed(command_id.0, true);
+        self.internal_emit_executed(command_id.0, selector);
 
-        let chain_id = data_tokens[0].clone().into_uint().unwrap().as_u64();
-        let command_ids = data_tokens[1]
-            .clone()
-            .into_array()
-            .unwrap()
-            .into_iter()
-            .map(|token| token.into_fixed_bytes().unwrap())
-            .collect::<Vec<_>>();
+        true
+    }
 
-        let commands = data_tokens[2]
-            .clone()
-            .into_array()
-            .unwrap()
-            .into_iter()
-            .map(|token| token.into_string().unwrap())
-            .collect::<Vec<_>>();
+    /// It fills in the results of the commands of a batch that were forwarded to the auth module
+    ///
+    /// Arguments:
+    ///
+    /// * `results`: The result of each command, `None` for the forwarded ones, in batch order.
+    ///
+    /// Returns:
+    ///
+    /// The result of the execution of each command.
+    #[private]
+    pub fn on_forwarded_commands(
+        &mut self,
+        #[serializer(borsh)] results: Vec<Option<bool>>,
+    ) -> Vec<bool> {
+        let mut forwarded = 0;
 
-        let params = data_tokens[3]
-            .clone()
-            .into_array()
-            .unwrap()
+        results
             .into_iter()
-            .map(|token| token.into_bytes().unwrap())
-            .collect::<Vec<_>>();
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    let success = match env::promise_result(forwarded) {
+                        PromiseResult::Successful(value) => {
+                            serde_json::from_slice(&value).unwrap_or(false)
+                        }
+                        _ => false,
+                    };
+
+                    forwarded += 1;
+
+                    success
+                })
+            })
+            .collect()
+    }
 
-        // TODO: Update to NEAR chain id which we need to decide on
-        if chain_id != 0 {
-            env::panic_str(format!("Invalid chain id: {}", chain_id).as_str());
-        }
+    // Only Owner functions
 
-        let commands_length = command_ids.len();
+    /// `approve_contract_call` is a function that is called by the `Bridge` contract on the source
+    /// chain to approve a contract call
+    ///
+    /// The command is marked as executed, so an operator-signed command with the same ID cannot
+    /// approve it again. Fails once admin approvals are disabled, see `disable_admin_approvals`.
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The parameters of the contract call.
+    /// * `command_id`: The ID of the command that was approved.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    pub fn approve_contract_call(&mut self, params: Bytes, command_id: Bytes32) -> bool {
+        self.internal_admin_override("approve_contract_call");
 
-        if commands_length != commands.len() || commands_length != params.len() {
-            env::panic_str("Invalid commands");
+        if self.admin_approvals_disabled {
+            env::panic_str("Admin approvals disabled");
         }
 
-        let mut call_results: Vec<bool> = Vec::new();
-
-        for i in 0..commands_length {
-            let command_id: [u8; 32] = command_ids[i].clone().try_into().unwrap();
-
-            if self.is_command_executed(format!("0x{}", hex::encode(command_id))) {
-                continue;
-            }
-
-            let command = commands[i].clone();
+        if self.is_command_executed(command_id) {
+            env::panic_str("Command already executed");
+        }
 
-            let success: bool;
+        let params = ContractCallParams::decode(&params.0, BatchEncoding::Abi)
+            .unwrap_or_else(|e| env::panic_str(&e));
 
-            match command.as_str() {
-                SELECTOR_APPROVE_CONTRACT_CALL => {
-                    self.internal_set_command_executed(command_id, true);
-                    success = self.internal_approve_contract_call(
-                        params[i].clone(),
-                        utils::to_eth_hex_string(command_id),
-                    );
-                }
-                SELECTOR_TRANSFER_OPERATORSHIP => {
-                    if !allow_operatorship_transfer {
-                        continue;
-                    }
+        self.internal_set_command_executed(command_id.0, true);
+        self.internal_approve_contract_call(params, command_id.0)
+    }
 
-                    allow_operatorship_transfer = false;
-                    self.internal_set_command_executed(command_id, true);
+    /// `disable_admin_approvals` permanently stops the owner from approving contract calls, so
+    /// only operator-signed batches can approve them from then on. Earlier versions of the gateway
+    /// did not mark owner approvals as executed, the command IDs of those approvals have to be
+    /// passed in so they are marked as well.
+    ///
+    /// Arguments:
+    ///
+    /// * `command_ids`: The command IDs of owner approvals that are not marked as executed.
+    #[payable]
+    pub fn disable_admin_approvals(&mut self, command_ids: Option<Vec<Bytes32>>) {
+        self.internal_admin_action("disable_admin_approvals");
 
-                    success = self.internal_transfer_operatorship(params[i].clone());
-                }
-                _ => {
-                    continue;
-                }
-            };
+        if self.admin_approvals_disabled {
+            env::panic_str("Admin approvals already disabled");
+        }
 
-            if success {
-                let event = ExecutedEvent {
-                    command_id: utils::to_eth_hex_string(command_id),
-                };
+        self.admin_approvals_disabled = true;
 
-                Event::emit(&event);
-            } else {
-                self.internal_set_command_executed(command_id, false);
-            }
+        let command_ids = command_ids.unwrap_or_default();
 
-            call_results.push(success);
+        for command_id in &command_ids {
+            self.internal_set_command_executed(command_id.0, true);
         }
 
-        call_results
+        AdminApprovalsDisabledEvent {
+            caller: env::predecessor_account_id().to_string(),
+            command_ids: command_ids.iter().map(|x| x.to_string()).collect(),
+        }
+        .emit();
     }
 
-    /// Only Owner functions
+    // View functions
 
-    /// `approve_contract_call` is a function that is called by the `Bridge` contract on the source
-    /// chain to approve a contract call
-    ///
-    /// Arguments:
-    ///
-    /// * `params`: The parameters of the contract call.
-    /// * `command_id`: The ID of the command that was approved.
+    /// It returns whether the owner can no longer approve contract calls
     ///
     /// Returns:
     ///
     /// A boolean value.
-    pub fn approve_contract_call(&mut self, params: String, command_id: String) -> bool {
-        Self::require_owner();
-        let payload = clean_payload(params.clone());
-        self.internal_approve_contract_call(payload, command_id)
+    pub fn admin_approvals_disabled(&self) -> bool {
+        self.admin_approvals_disabled
     }
 
-    // View functions
-
     /// It returns a boolean value indicating whether a contract call has been approved
     ///
+    /// It answers while the gateway is paused too, only validating the call is paused.
+    ///
     /// Arguments:
     ///
     /// * `command_id`: The command ID of the contract call.
@@ -214,35 +281,23 @@ impl Axelar {
     /// A boolean value.
     pub fn is_contract_call_approved(
         &self,
-        command_id: String,
+        command_id: Bytes32,
         source_chain: String,
         source_address: String,
         contract_address: String,
-        payload_hash: String,
+        payload_hash: Bytes32,
     ) -> bool {
-        let command: [u8; 32] = clean_payload(command_id).try_into().unwrap();
-        let payload = clean_payload(payload_hash);
-
         let key = self.internal_get_is_contract_call_approved_key(
-            command,
+            command_id.0,
             source_chain,
             source_address,
             contract_address,
-            payload.try_into().unwrap(),
+            payload_hash.0,
         );
 
         self.bool_state.get(&key).unwrap_or(false)
     }
 
-    /// `auth_module` returns the account id of the current account
-    ///
-    /// Returns:
-    ///
-    /// The account id of the current account.
-    pub fn auth_module(&self) -> AccountId {
-        env::current_account_id()
-    }
-
     /// `is_command_executed` returns `true` if the command with the given `command_id` has been
     /// executed, and `false` otherwise
     ///
@@ -253,9 +308,8 @@ impl Axelar {
     /// Returns:
     ///
     /// A boolean value.
-    pub fn is_command_executed(&self, command_id: String) -> bool {
-        let command: [u8; 32] = clean_payload(command_id).try_into().unwrap();
-        let key = self.internal_get_is_command_executed_key(command);
+    pub fn is_command_executed(&self, command_id: Bytes32) -> bool {
+        let key = self.internal_get_is_command_executed_key(command_id.0);
         self.bool_state.get(&key).unwrap_or(false)
     }
 
@@ -277,20 +331,19 @@ impl Axelar {
     #[payable]
     pub fn validate_contract_call(
         &mut self,
-        command_id: String,
+        command_id: Bytes32,
         source_chain: String,
         source_address: String,
-        payload_hash: String,
+        payload_hash: Bytes32,
     ) -> bool {
-        let command: [u8; 32] = clean_payload(command_id).try_into().unwrap();
-        let payload = clean_payload(payload_hash);
+        Self::require_unpaused();
 
         let key = self.internal_get_is_contract_call_approved_key(
-            command,
+            command_id.0,
             source_chain,
             source_address,
             predecessor_account_id().to_string(),
-            payload.try_into().unwrap(),
+            payload_hash.0,
         );
 
         let valid = self.bool_state.get(&key).unwrap_or(false);
@@ -304,59 +357,229 @@ impl Axelar {
 
     // Internal functions
 
-    /// `internal_approve_contract_call` is a function that is called by the `approve_contract_call`
-    /// function in the `Bridge` contract
+    /// It executes the commands of a batch whose proof has been checked
     ///
     /// Arguments:
     ///
-    /// * `payload`: The payload of the contract call.
-    /// * `command_id`: The ID of the command that was approved.
+    /// * `batch`: The command batch.
+    /// * `signers_epoch`: The epoch of the operators that signed the proof if they are the current
+    ///   ones, `None` otherwise. Commands forwarded to the auth module are rejected once it
+    ///   rotated past this epoch.
     ///
     /// Returns:
     ///
-    /// A boolean value.
-    fn internal_approve_contract_call(&mut self, payload: Vec<u8>, command_id: String) -> bool {
-        Self::require_owner();
+    /// The result of the execution of each command, once the commands forwarded to the auth
+    /// module resolved.
+    fn internal_execute(
+        &mut self,
+        batch: CommandBatch,
+        signers_epoch: Option<u64>,
+    ) -> PromiseOrValue<Vec<bool>> {
+        // Operatorship transfers and admin commands need the operators of the proof to be current
+        let mut signers_epoch = signers_epoch;
+
+        let CommandBatch {
+            chain_id,
+            command_ids,
+            commands,
+            params,
+            encoding,
+        } = batch;
+
+        // TODO: Update to NEAR chain id which we need to decide on
+        if chain_id != 0 {
+            env::panic_str(format!("Invalid chain id: {}", chain_id).as_str());
+        }
+
+        let commands_length = command_ids.len();
+
+        if commands_length != commands.len() || commands_length != params.len() {
+            env::panic_str("Invalid commands");
+        }
+
+        // Commands forwarded to the auth module have no result until it answered
+        let mut call_results: Vec<Option<bool>> = Vec::new();
+        let mut forwarded: Option<Promise> = None;
+
+        for i in 0..commands_length {
+            let command_id = command_ids[i];
+
+            if self.is_command_executed(Bytes32(command_id)) {
+                continue;
+            }
+
+            let command = commands[i].clone();
+
+            let result = match command.as_str() {
+                SELECTOR_APPROVE_CONTRACT_CALL => {
+                    if Self::is_paused() {
+                        continue;
+                    }
+
+                    let params = ContractCallParams::decode(&params[i], encoding)
+                        .unwrap_or_else(|e| env::panic_str(&e));
+
+                    PromiseOrValue::Value(self.internal_approve_contract_call(params, command_id))
+                }
+                SELECTOR_TRANSFER_OPERATORSHIP => {
+                    let expected_epoch = match signers_epoch.take() {
+                        Some(epoch) => epoch,
+                        None => continue,
+                    };
+
+                    let new_signers = WeightedSigners::decode_params(&params[i], encoding)
+                        .unwrap_or_else(|e| env::panic_str(&e));
+
+                    match self.auth_module.clone() {
+                        None => {
+                            PromiseOrValue::Value(self.internal_rotate_signers(new_signers, true))
+                        }
+                        Some(auth_module) => {
+                            let mut params = vec![BORSH_ENCODING_VERSION];
+                            params.extend(new_signers.try_to_vec().unwrap());
+
+                            PromiseOrValue::Promise(
+                                ext_auth_module::ext(auth_module)
+                                    .transfer_operatorship_from_gateway(
+                                        Bytes(params),
+                                        expected_epoch,
+                                    ),
+                            )
+                        }
+                    }
+                }
+                command if ADMIN_SELECTORS.contains(&command) => {
+                    let expected_epoch = match signers_epoch {
+                        Some(epoch) => epoch,
+                        None => continue,
+                    };
+
+                    self.internal_execute_admin_command(
+                        command,
+                        params[i].clone(),
+                        encoding,
+                        expected_epoch,
+                    )
+                }
+                _ => {
+                    continue;
+                }
+            };
+
+            match result {
+                PromiseOrValue::Value(success) => {
+                    if success {
+                        self.internal_set_command_executed(command_id, true);
+                        self.internal_emit_executed(command_id, command);
+                    }
+
+                    call_results.push(Some(success));
+                }
+                PromiseOrValue::Promise(promise) => {
+                    let promise = promise.then(
+                        Self::ext(env::current_account_id())
+                            .on_auth_module_command(Bytes32(command_id), command),
+                    );
 
-        let expected_output_types = vec![
-            ParamType::String,
-            ParamType::String,
-            ParamType::String,
-            ParamType::FixedBytes(32),
-            ParamType::FixedBytes(32),
-            ParamType::Uint(256),
-        ];
+                    forwarded = Some(match forwarded {
+                        None => promise,
+                        Some(forwarded) => forwarded.and(promise),
+                    });
 
-        let tokens = abi_decode(&payload, &expected_output_types).unwrap();
+                    call_results.push(None);
+                }
+            }
+        }
 
-        let source_chain = tokens[0].clone().into_string().unwrap();
-        let source_address = tokens[1].clone().into_string().unwrap();
-        let contract_address = tokens[2].clone().into_string().unwrap();
-        let payload_hash = tokens[3].clone().into_fixed_bytes().unwrap();
-        let source_tx_hash = tokens[4].clone().into_fixed_bytes().unwrap();
-        let source_event_index = tokens[5].clone().into_uint().unwrap().as_u64(); // Crashes
+        match forwarded {
+            None => PromiseOrValue::Value(call_results.into_iter().flatten().collect()),
+            Some(forwarded) => PromiseOrValue::Promise(
+                forwarded.then(
+                    Self::ext(env::current_account_id()).on_forwarded_commands(call_results),
+                ),
+            ),
+        }
+    }
 
-        let command = clean_payload(command_id.clone()).try_into().unwrap();
+    /// It emits the events of an executed command
+    ///
+    /// Arguments:
+    ///
+    /// * `command_id`: The ID of the command.
+    /// * `selector`: The selector of the command.
+    fn internal_emit_executed(&self, command_id: [u8; 32], selector: String) {
+        let event = ExecutedEvent {
+            command_id: utils::to_eth_hex_string(command_id),
+        };
+
+        let event_v2 = ExecutedEventV2 {
+            command_id: event.command_id.clone(),
+            selector,
+        };
+
+        Event::emit(&event);
+        Event::emit(&event_v2);
+    }
+
+    /// `internal_approve_contract_call` is a function that is called by the `approve_contract_call`
+    /// function in the `Bridge` contract
+    ///
+    /// It does not check the caller, its callers do: `approve_contract_call` and `execute` only
+    /// let the owner through.
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The decoded parameters of the contract call.
+    /// * `command_id`: The ID of the command that was approved.
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    fn internal_approve_contract_call(
+        &mut self,
+        params: ContractCallParams,
+        command_id: [u8; 32],
+    ) -> bool {
+        let ContractCallParams {
+            source_chain,
+            source_address,
+            contract_address,
+            payload_hash,
+            source_tx_hash,
+            source_event_index,
+        } = params;
 
         self.internal_set_contract_call_approved(
-            command,
+            command_id,
             source_chain.clone(),
             source_address.clone(),
             contract_address.clone(),
-            payload_hash.clone().try_into().unwrap(),
+            payload_hash,
         );
 
         let event = ContractCallApprovedEvent {
-            command_id,
+            command_id: utils::to_eth_hex_string(command_id),
             source_chain,
             source_address,
             contract_address,
-            payload_hash: utils::to_eth_hex_string(payload_hash.try_into().unwrap()),
-            source_tx_hash: utils::to_eth_hex_string(source_tx_hash.try_into().unwrap()),
+            payload_hash: utils::to_eth_hex_string(payload_hash),
+            source_tx_hash: utils::to_eth_hex_string(source_tx_hash),
+            source_event_index,
+        };
+
+        let event_v2 = ContractCallApprovedEventV2 {
+            command_id: event.command_id.clone(),
+            source_chain: event.source_chain.clone(),
+            source_address: event.source_address.clone(),
+            contract_address: event.contract_address.clone(),
+            payload_hash: event.payload_hash.clone(),
+            source_tx_hash: event.source_tx_hash.clone(),
             source_event_index,
+            approval_block_height: env::block_height(),
         };
 
         Event::emit(&event);
+        Event::emit(&event_v2);
 
         true
     }
@@ -394,7 +617,7 @@ impl Axelar {
     /// Returns:
     ///
     /// The keccak256 hash of the encoded data.
-    fn internal_get_is_contract_call_approved_key(
+    pub(crate) fn internal_get_is_contract_call_approved_key(
         &self,
         command_id: [u8; 32],
         source_chain: String,
@@ -421,7 +644,7 @@ impl Axelar {
     ///
     /// * `command_id`: The command ID of the command that was executed.
     /// * `executed`: bool - whether the command has been executed or not
-    fn internal_set_command_executed(&mut self, command_id: [u8; 32], executed: bool) {
+    pub(crate) fn internal_set_command_executed(&mut self, command_id: [u8; 32], executed: bool) {
         let key = self.internal_get_is_command_executed_key(command_id);
         self.bool_state.insert(&key, &executed);
     }
@@ -435,7 +658,7 @@ impl Axelar {
     /// * `source_address`: The address of the contract that is calling the target contract.
     /// * `contract_address`: The address of the contract that is being called.
     /// * `payload_hash`: The hash of the payload that was sent to the contract.
-    fn internal_set_contract_call_approved(
+    pub(crate) fn internal_set_contract_call_approved(
         &mut self,
         command_id: [u8; 32],
         source_chain: String,
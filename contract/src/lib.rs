@@ -3,17 +3,23 @@
  *
  */
 
+mod admin;
+mod amplifier;
+mod auth_module;
 mod auth_weighted;
 mod events;
 mod gateway;
+mod ownership;
+mod types;
 mod utils;
+mod verifier;
 
-use near_contract_tools::{owner::*, Owner};
+use near_contract_tools::{owner::*, Pause};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::LookupMap;
 use near_sdk::env::predecessor_account_id;
-use near_sdk::near_bindgen;
-use utils::clean_payload;
+use near_sdk::{env, near_bindgen, AccountId};
+use types::{Bytes, ProofVerifier, ProposedOwner, SigningDomain, WeightedSigners};
 use utils::keccak256;
 
 /// `Axelar` is a struct that contains a `current_epoch` field, a `hash_for_epoch` field, an
@@ -26,26 +32,62 @@ use utils::keccak256;
 ///
 /// Properties:
 ///
+/// * `auth_module`: The account id of the contract that checks proofs, `None` to check them in this
+///   contract.
+/// * `chain_name`: The name of the chain the gateway is deployed on, part of its signing domain.
+/// * `signing_domain`: The signing domain operators sign proofs in.
+/// * `proof_verifier`: The proof system proofs are checked with.
 /// * `current_epoch`: The current epoch number.
+/// * `old_key_retention`: The number of epochs an operator set stays valid for after it was
+///   rotated out.
+/// * `minimum_rotation_delay`: The minimum time between signer-driven rotations, in nanoseconds.
+/// * `last_rotation_timestamp`: The block timestamp of the last rotation, in nanoseconds.
+/// * `pruned_epoch`: The most recent epoch whose operator set has been pruned from storage.
 /// * `hash_for_epoch`: This is a map that stores the hash of the block that was used to create the
 /// epoch.
 /// * `epoch_for_hash`: This is a mapping from a hash to an epoch.
+/// * `signers_for_epoch`: This is a mapping from an epoch to the decoded operator set registered
+///   at it.
 /// * `prefix_command_executed`: This is the prefix for the key that stores the boolean value of whether
 /// a command has been executed.
 /// * `prefix_contract_call_approved`: This is the prefix for the key that stores the boolean value of
 /// whether a contract call has been approved.
+/// * `prefix_message_executed`: This is the prefix for the key that stores the boolean value of
+/// whether an approved message has been executed.
 /// * `bool_state`: This is a map that stores the state of the contract.
+/// * `staged_code`: The contract code staged for `upgrade` commands, by sha256 hash.
+/// * `proposed_owner`: The pending ownership transfer, if any.
+/// * `admin_action_count`: The number of calls the owner has made.
+/// * `admin_override_count`: The number of owner calls that approved contract calls or rotated the
+///   operators in place of the operators.
+/// * `admin_approvals_disabled`: Whether the owner can no longer approve contract calls.
 #[near_bindgen]
-#[derive(Owner, BorshDeserialize, BorshSerialize)]
+#[derive(Pause, BorshDeserialize, BorshSerialize)]
 pub struct Axelar {
     // Auth Weighted
+    auth_module: Option<AccountId>,
+    chain_name: String,
+    signing_domain: SigningDomain,
+    proof_verifier: ProofVerifier,
     current_epoch: u64,
+    old_key_retention: u64,
+    minimum_rotation_delay: u64,
+    last_rotation_timestamp: u64,
+    pruned_epoch: u64,
     hash_for_epoch: LookupMap<u64, [u8; 32]>,
     epoch_for_hash: LookupMap<[u8; 32], u64>,
+    signers_for_epoch: LookupMap<u64, WeightedSigners>,
     // Gateway
     prefix_command_executed: [u8; 32],
     prefix_contract_call_approved: [u8; 32],
+    prefix_message_executed: [u8; 32],
     bool_state: LookupMap<[u8; 32], bool>,
+    // Admin
+    staged_code: LookupMap<[u8; 32], Vec<u8>>,
+    proposed_owner: Option<ProposedOwner>,
+    admin_action_count: u64,
+    admin_override_count: u64,
+    admin_approvals_disabled: bool,
 }
 
 /// This is a default implementation of the `Axelar` struct.
@@ -53,13 +95,29 @@ impl Default for Axelar {
     fn default() -> Self {
         Self {
             // Auth Weighted
+            auth_module: None,
+            chain_name: auth_weighted::DEFAULT_CHAIN_NAME.to_string(),
+            signing_domain: SigningDomain::Legacy,
+            proof_verifier: ProofVerifier::WeightedEcdsa,
             current_epoch: 0,
+            old_key_retention: auth_weighted::DEFAULT_OLD_KEY_RETENTION,
+            minimum_rotation_delay: 0,
+            last_rotation_timestamp: 0,
+            pruned_epoch: 0,
             hash_for_epoch: LookupMap::new(b"hash_for_epoch".to_vec()),
             epoch_for_hash: LookupMap::new(b"epoch_for_hash".to_vec()),
+            signers_for_epoch: LookupMap::new(b"signers_for_epoch".to_vec()),
             // Gateway
             prefix_command_executed: keccak256(b"command-executed"),
             prefix_contract_call_approved: keccak256(b"contract-call-approved"),
+            prefix_message_executed: keccak256(b"message-executed"),
             bool_state: LookupMap::new(b"bool_state".to_vec()),
+            // Admin
+            staged_code: LookupMap::new(b"staged_code".to_vec()),
+            proposed_owner: None,
+            admin_action_count: 0,
+            admin_override_count: 0,
+            admin_approvals_disabled: false,
         }
     }
 }
@@ -70,28 +128,157 @@ impl Axelar {
     ///
     /// Arguments:
     ///
-    /// * `recent_operators`: A list of account IDs that will be given operator status.
+    /// * `recent_operators`: A list of ABI-encoded operator sets, as `0x`-prefixed hex or Base64.
+    /// * `old_key_retention`: The number of epochs an operator set stays valid for, defaults to
+    ///   `DEFAULT_OLD_KEY_RETENTION`.
+    /// * `chain_name`: The chain name of the signing domain, defaults to `DEFAULT_CHAIN_NAME`.
+    /// * `signing_domain`: The signing domain proofs are checked in, defaults to `Legacy`.
+    /// * `auth_module`: The account id of a separate auth module, defaults to this contract.
+    /// * `proof_verifier`: The proof system proofs are checked with, defaults to `WeightedEcdsa`.
     ///
     /// Returns:
     ///
     /// The contract is being returned.
     #[init]
-    pub fn new(recent_operators: Vec<String>) -> Self {
+    pub fn new(
+        recent_operators: Vec<Bytes>,
+        old_key_retention: Option<u64>,
+        chain_name: Option<String>,
+        signing_domain: Option<SigningDomain>,
+        auth_module: Option<AccountId>,
+        proof_verifier: Option<ProofVerifier>,
+    ) -> Self {
         let mut contract = Self {
-            // Auth Weighted
-            current_epoch: 0,
-            hash_for_epoch: LookupMap::new(b"hash_for_epoch".to_vec()),
-            epoch_for_hash: LookupMap::new(b"epoch_for_hash".to_vec()),
-            // Gateway
-            prefix_command_executed: keccak256(b"command-executed"),
-            prefix_contract_call_approved: keccak256(b"contract-call-approved"),
-            bool_state: LookupMap::new(b"bool_state".to_vec()),
+            auth_module: auth_module.filter(|x| *x != env::current_account_id()),
+            proof_verifier: proof_verifier.unwrap_or(ProofVerifier::WeightedEcdsa),
+            ..Self::default()
         };
 
+        if let Some(chain_name) = chain_name {
+            contract.chain_name = chain_name;
+        }
+
+        if let Some(signing_domain) = signing_domain {
+            contract.signing_domain = signing_domain;
+        }
+
+        if let Some(old_key_retention) = old_key_retention {
+            if old_key_retention == 0 {
+                env::panic_str("Invalid key retention");
+            }
+
+            contract.old_key_retention = old_key_retention;
+        }
+
         Owner::init(&mut contract, &predecessor_account_id());
+        env::storage_write(admin::STATE_VERSION_KEY, &[admin::STATE_VERSION]);
+
+        if contract.auth_module.is_some() && !recent_operators.is_empty() {
+            env::panic_str("Operators are managed by the auth module");
+        }
 
         for operator in recent_operators {
-            contract.internal_transfer_operatorship(clean_payload(operator));
+            contract.internal_transfer_operatorship(operator.0, false);
+        }
+
+        contract
+    }
+
+    /// `new_with_signers` is the JSON-native counterpart of `new`, it encodes each operator set in
+    /// its canonical form, so the stored hashes match the ones signed batches reference
+    ///
+    /// Arguments:
+    ///
+    /// * `recent_signers`: A list of operator sets, oldest first, each with its operators, weights,
+    ///   threshold and optional nonce. Operators may be given in any order.
+    /// * `old_key_retention`: The number of epochs an operator set stays valid for, defaults to
+    ///   `DEFAULT_OLD_KEY_RETENTION`.
+    /// * `chain_name`: The chain name of the signing domain, defaults to `DEFAULT_CHAIN_NAME`.
+    /// * `signing_domain`: The signing domain proofs are checked in, defaults to `Legacy`.
+    ///
+    /// Returns:
+    ///
+    /// The contract is being returned.
+    #[init]
+    pub fn new_with_signers(
+        recent_signers: Vec<WeightedSigners>,
+        old_key_retention: Option<u64>,
+        chain_name: Option<String>,
+        signing_domain: Option<SigningDomain>,
+    ) -> Self {
+        let mut contract = Self::new(
+            vec![],
+            old_key_retention,
+            chain_name,
+            signing_domain,
+            None,
+            None,
+        );
+
+        for signers in recent_signers {
+            let signers = signers
+                .into_canonical()
+                .unwrap_or_else(|e| env::panic_str(&e));
+
+            contract.internal_rotate_signers(signers, false);
+        }
+
+        contract
+    }
+
+    /// `new_from_epochs` is the counterpart of `new` for a gateway that continues the epochs of an
+    /// existing deployment, so its epoch numbers match the ones the Axelar network tracks
+    ///
+    /// Arguments:
+    ///
+    /// * `operator_sets`: The epoch and ABI-encoded operator set of every epoch in the key retention
+    ///   window, oldest first. Each set emits the same events as a transfer of operatorship.
+    /// * `current_epoch`: The epoch of the last operator set.
+    /// * `old_key_retention`: The number of epochs an operator set stays valid for, defaults to
+    ///   `DEFAULT_OLD_KEY_RETENTION`.
+    /// * `chain_name`: The chain name of the signing domain, defaults to `DEFAULT_CHAIN_NAME`.
+    /// * `signing_domain`: The signing domain proofs are checked in, defaults to `Legacy`.
+    ///
+    /// Returns:
+    ///
+    /// The contract is being returned.
+    #[init]
+    pub fn new_from_epochs(
+        operator_sets: Vec<(u64, Bytes)>,
+        current_epoch: u64,
+        old_key_retention: Option<u64>,
+        chain_name: Option<String>,
+        signing_domain: Option<SigningDomain>,
+    ) -> Self {
+        let mut contract = Self::new(
+            vec![],
+            old_key_retention,
+            chain_name,
+            signing_domain,
+            None,
+            None,
+        );
+
+        if current_epoch == 0 {
+            env::panic_str("Invalid current epoch");
+        }
+
+        // Every epoch still in the key retention window has to be imported, older ones count as pruned
+        let first_epoch = current_epoch - contract.old_key_retention.min(current_epoch) + 1;
+
+        if operator_sets.len() as u64 != current_epoch - first_epoch + 1 {
+            env::panic_str("Key retention window not covered");
+        }
+
+        contract.current_epoch = first_epoch - 1;
+        contract.pruned_epoch = first_epoch - 1;
+
+        for (epoch, operators) in operator_sets {
+            if epoch != contract.current_epoch + 1 {
+                env::panic_str("Invalid epochs");
+            }
+
+            contract.internal_transfer_operatorship(operators.0, false);
         }
 
         contract
@@ -0,0 +1,198 @@
+/*
+ * Axelar Gateway Ownership
+ *
+ */
+use crate::events::{
+    OwnershipTransferCancelledEvent, OwnershipTransferProposedEvent, OwnershipTransferredEvent,
+};
+use crate::types::ProposedOwner;
+use crate::{Axelar, AxelarExt};
+use near_contract_tools::owner::*;
+use near_contract_tools::standard::nep297::Event;
+use near_sdk::{assert_one_yocto, env, near_bindgen, AccountId};
+
+/// How long a proposed owner has to accept ownership, unless another expiry is proposed.
+pub const DEFAULT_OWNERSHIP_PROPOSAL_TTL: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+/// Ownership is read from the `near_contract_tools` owner slot, the transfer itself is two-step
+/// with an expiry, see `own_propose_owner`.
+impl Owner for Axelar {}
+
+/// Axelar Gateway Ownership Implementation
+#[near_bindgen]
+impl Axelar {
+    /// `own_get_owner` returns the owner of the gateway
+    ///
+    /// Returns:
+    ///
+    /// The account id of the owner.
+    pub fn own_get_owner(&self) -> Option<AccountId> {
+        Self::slot_owner().read()
+    }
+
+    /// `own_get_proposed_owner` returns the account of the pending ownership transfer, if it has
+    /// not expired
+    ///
+    /// Returns:
+    ///
+    /// The account id of the proposed owner.
+    pub fn own_get_proposed_owner(&self) -> Option<AccountId> {
+        self.own_get_ownership_proposal().map(|x| x.account_id)
+    }
+
+    /// `own_get_ownership_proposal` returns the pending ownership transfer, if it has not expired
+    ///
+    /// Returns:
+    ///
+    /// The proposed owner and when the proposal expires.
+    pub fn own_get_ownership_proposal(&self) -> Option<ProposedOwner> {
+        self.proposed_owner
+            .clone()
+            .filter(|x| x.expires_at > env::block_timestamp())
+    }
+
+    // Only owner
+
+    /// `own_renounce_owner` leaves the gateway without an owner and drops the pending ownership
+    /// transfer. Owner only methods cannot be called afterwards.
+    #[payable]
+    pub fn own_renounce_owner(&mut self) {
+        assert_one_yocto();
+        self.internal_admin_action("own_renounce_owner");
+
+        self.internal_transfer_ownership(None);
+    }
+
+    /// `own_propose_owner` proposes a new owner, which has to accept ownership within
+    /// `DEFAULT_OWNERSHIP_PROPOSAL_TTL`. A new proposal replaces the pending one.
+    ///
+    /// Arguments:
+    ///
+    /// * `account_id`: The account that can accept ownership, `None` cancels the pending
+    ///   proposal.
+    #[payable]
+    pub fn own_propose_owner(&mut self, account_id: Option<AccountId>) {
+        assert_one_yocto();
+        self.internal_admin_action("own_propose_owner");
+
+        match account_id {
+            Some(account_id) => {
+                self.internal_propose_owner(account_id, DEFAULT_OWNERSHIP_PROPOSAL_TTL)
+            }
+            None => {
+                self.internal_cancel_proposed_owner();
+            }
+        }
+    }
+
+    /// `own_propose_owner_with_expiry` proposes a new owner, which has to accept ownership before
+    /// the proposal expires. A new proposal replaces the pending one.
+    ///
+    /// Arguments:
+    ///
+    /// * `account_id`: The account that can accept ownership.
+    /// * `expires_in`: How long the proposal stays valid for, in nanoseconds.
+    #[payable]
+    pub fn own_propose_owner_with_expiry(&mut self, account_id: AccountId, expires_in: u64) {
+        assert_one_yocto();
+        self.internal_admin_action("own_propose_owner_with_expiry");
+
+        if expires_in == 0 {
+            env::panic_str("Invalid expiry");
+        }
+
+        self.internal_propose_owner(account_id, expires_in);
+    }
+
+    /// `own_cancel_proposed_owner` cancels the pending ownership transfer
+    #[payable]
+    pub fn own_cancel_proposed_owner(&mut self) {
+        assert_one_yocto();
+        self.internal_admin_action("own_cancel_proposed_owner");
+
+        if !self.internal_cancel_proposed_owner() {
+            env::panic_str("No proposed owner");
+        }
+    }
+
+    // Only proposed owner
+
+    /// `own_accept_owner` makes the proposed owner the owner, unless the proposal expired
+    #[payable]
+    pub fn own_accept_owner(&mut self) {
+        assert_one_yocto();
+
+        let proposed_owner = self
+            .own_get_ownership_proposal()
+            .unwrap_or_else(|| env::panic_str("No proposed owner"));
+
+        if env::predecessor_account_id() != proposed_owner.account_id {
+            env::panic_str("Proposed owner only");
+        }
+
+        self.internal_transfer_ownership(Some(proposed_owner.account_id));
+    }
+
+    // Internal
+
+    /// It proposes a new owner, replacing the pending proposal
+    ///
+    /// Arguments:
+    ///
+    /// * `account_id`: The account that can accept ownership.
+    /// * `expires_in`: How long the proposal stays valid for, in nanoseconds.
+    fn internal_propose_owner(&mut self, account_id: AccountId, expires_in: u64) {
+        let proposed_owner = ProposedOwner {
+            account_id,
+            expires_at: env::block_timestamp().saturating_add(expires_in),
+        };
+
+        OwnershipTransferProposedEvent {
+            owner: env::predecessor_account_id().to_string(),
+            proposed_owner: proposed_owner.account_id.to_string(),
+            expires_at: proposed_owner.expires_at,
+        }
+        .emit();
+
+        self.proposed_owner = Some(proposed_owner);
+    }
+
+    /// It drops the pending ownership transfer
+    ///
+    /// Returns:
+    ///
+    /// A boolean value indicating whether a transfer was pending.
+    fn internal_cancel_proposed_owner(&mut self) -> bool {
+        match self.proposed_owner.take() {
+            Some(proposed_owner) => {
+                OwnershipTransferCancelledEvent {
+                    owner: env::predecessor_account_id().to_string(),
+                    proposed_owner: proposed_owner.account_id.to_string(),
+                }
+                .emit();
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// It makes an account the owner and drops the pending ownership transfer. Only
+    /// `OwnershipTransferredEvent` is emitted, not the `near_contract_tools` transfer event.
+    ///
+    /// Arguments:
+    ///
+    /// * `new_owner`: The new owner, `None` leaves the gateway without an owner.
+    pub(crate) fn internal_transfer_ownership(&mut self, new_owner: Option<AccountId>) {
+        let previous_owner = Self::slot_owner().read();
+
+        self.proposed_owner = None;
+        self.update_owner_unchecked(new_owner.clone());
+
+        OwnershipTransferredEvent {
+            previous_owner: previous_owner.map(|x| x.to_string()).unwrap_or_default(),
+            new_owner: new_owner.map(|x| x.to_string()).unwrap_or_default(),
+        }
+        .emit();
+    }
+}
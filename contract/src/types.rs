@@ -0,0 +1,821 @@
+/*
+ * Axelar argument and encoding types
+ *
+ */
+use crate::utils::{abi_decode, abi_encode, keccak256};
+use ethabi::ethereum_types::U256;
+use ethabi::{ParamType, Token};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::de;
+use near_sdk::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use near_sdk::{AccountId, CurveType, PublicKey};
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+use uint::hex;
+
+pub use axelar_executable_near::types::{Bytes, Bytes32};
+
+/// Version byte that prefixes Borsh-encoded inputs and proofs. ABI-encoded inputs always start
+/// with a zero byte, because their first word is a small offset.
+pub const BORSH_ENCODING_VERSION: u8 = 1;
+
+/// Domain tag hashed together with a Borsh-encoded command batch, so a signature over a Borsh
+/// batch can never be replayed as a signature over an ABI batch and vice versa.
+pub const BORSH_BATCH_DOMAIN_TAG: &[u8] = b"axelar-near-borsh-batch-v1";
+
+/// Protocol tag of the NEAR signing domain, see `Axelar::domain_separator`.
+pub const NEAR_SIGNING_DOMAIN_TAG: &str = "axelar-near-gateway-v1";
+
+/// `Uint256` is a 256-bit unsigned integer.
+///
+/// It is Borsh-encoded as 32 little-endian bytes. In JSON it accepts a number or a decimal
+/// string, and always serializes as a decimal string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Uint256(pub U256);
+
+/// `EvmAddress` is a 20-byte Ethereum-style operator address.
+///
+/// It is Borsh-encoded as its 20 raw bytes. In JSON it is a `0x`-prefixed hex string.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize,
+)]
+pub struct EvmAddress(pub [u8; 20]);
+
+/// `OperatorKey` is the key of an operator, tagged with the scheme its signatures are checked with.
+///
+/// It is Borsh-encoded as a one byte tag (`0` for secp256k1, `1` for ed25519) followed by the
+/// raw key. In JSON a secp256k1 operator is its `0x`-prefixed address, and an ed25519 operator
+/// is its NEAR public key string, e.g. `ed25519:<base58 key>`.
+///
+/// Properties:
+///
+/// * `Secp256k1`: An Ethereum-style address, recovered from 65-byte ECDSA signatures.
+/// * `Ed25519`: A 32-byte public key, verified against 64-byte ed25519 signatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize)]
+pub enum OperatorKey {
+    Secp256k1(EvmAddress),
+    Ed25519([u8; 32]),
+}
+
+impl BorshSerialize for Uint256 {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut bytes = [0u8; 32];
+        self.0.to_little_endian(&mut bytes);
+        writer.write_all(&bytes)
+    }
+}
+
+impl BorshDeserialize for Uint256 {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        let bytes = <[u8; 32] as BorshDeserialize>::deserialize(buf)?;
+        Ok(Self(U256::from_little_endian(&bytes)))
+    }
+}
+
+impl fmt::Display for Uint256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Uint256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Uint256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Uint256Visitor;
+
+        impl<'de> de::Visitor<'de> for Uint256Visitor {
+            type Value = Uint256;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a non-negative integer or a decimal string")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(Uint256(value.into()))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                U256::from_dec_str(value)
+                    .map(Uint256)
+                    .map_err(|e| E::custom(format!("Invalid uint256: {:?}", e)))
+            }
+        }
+
+        deserializer.deserialize_any(Uint256Visitor)
+    }
+}
+
+impl fmt::Display for EvmAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl FromStr for EvmAddress {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let bytes = value
+            .strip_prefix("0x")
+            .ok_or_else(|| format!("Invalid address: {}", value))
+            .and_then(|hex_value| {
+                hex::decode(hex_value).map_err(|e| format!("Invalid address: {}", e))
+            })?;
+
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| format!("Invalid address: {}", value))
+    }
+}
+
+impl Serialize for EvmAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for EvmAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+impl OperatorKey {
+    /// It checks whether the key is all zero bytes, which no operator can sign for
+    ///
+    /// Returns:
+    ///
+    /// A boolean value.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            OperatorKey::Secp256k1(address) => *address == EvmAddress::default(),
+            OperatorKey::Ed25519(public_key) => *public_key == [0u8; 32],
+        }
+    }
+
+    /// It returns the tagged key, the same bytes as its Borsh encoding
+    ///
+    /// Returns:
+    ///
+    /// `0x00 || address` for secp256k1 operators, `0x01 || public key` for ed25519 operators.
+    pub fn to_tagged_bytes(self) -> Vec<u8> {
+        self.try_to_vec().unwrap()
+    }
+}
+
+impl From<EvmAddress> for OperatorKey {
+    fn from(address: EvmAddress) -> Self {
+        OperatorKey::Secp256k1(address)
+    }
+}
+
+impl fmt::Display for OperatorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OperatorKey::Secp256k1(address) => write!(f, "{}", address),
+            OperatorKey::Ed25519(public_key) => {
+                let mut bytes = vec![CurveType::ED25519 as u8];
+                bytes.extend_from_slice(public_key);
+
+                let public_key = PublicKey::try_from(bytes).map_err(|_| fmt::Error)?;
+                write!(f, "{}", String::from(&public_key))
+            }
+        }
+    }
+}
+
+impl FromStr for OperatorKey {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.starts_with("0x") {
+            return value.parse().map(OperatorKey::Secp256k1);
+        }
+
+        let public_key =
+            PublicKey::from_str(value).map_err(|e| format!("Invalid operator key: {}", e))?;
+
+        match public_key.curve_type() {
+            CurveType::ED25519 => public_key.as_bytes()[1..]
+                .try_into()
+                .map(OperatorKey::Ed25519)
+                .map_err(|_| format!("Invalid operator key: {}", value)),
+            CurveType::SECP256K1 => Err(format!(
+                "Invalid operator key: secp256k1 operators are given by address, not {}",
+                value
+            )),
+        }
+    }
+}
+
+impl Serialize for OperatorKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OperatorKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+/// `ExecuteInput` is the signed input of `execute`: the encoded command batch and the proof
+/// of the operators over it.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ExecuteInput {
+    pub data: Vec<u8>,
+    pub proof: Vec<u8>,
+}
+
+/// `BatchEncoding` is the format a command batch was decoded from. The parameters of its commands
+/// are encoded in the same format, Borsh-encoded parameters without a version byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum BatchEncoding {
+    Abi,
+    Borsh,
+}
+
+/// `CommandBatch` is the decoded `data` of an `execute` input.
+///
+/// Properties:
+///
+/// * `chain_id`: The chain id the batch was signed for.
+/// * `command_ids`: The unique id of each command.
+/// * `commands`: The selector of each command.
+/// * `params`: The encoded parameters of each command.
+/// * `encoding`: The format of the batch and of the parameters of its commands.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CommandBatch {
+    pub chain_id: u64,
+    pub command_ids: Vec<[u8; 32]>,
+    pub commands: Vec<String>,
+    pub params: Vec<Vec<u8>>,
+    pub encoding: BatchEncoding,
+}
+
+/// `WeightedSigners` is a weighted operator set.
+///
+/// Properties:
+///
+/// * `operators`: The operator keys, sorted in ascending order: secp256k1 addresses first, then
+///   ed25519 public keys.
+/// * `weights`: The weight of each operator.
+/// * `threshold`: The minimum accumulated weight of a valid proof.
+/// * `nonce`: A 32-byte nonce that is hashed together with the set, so the same operators can
+///   be registered again deliberately. Defaults to zero.
+#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WeightedSigners {
+    pub operators: Vec<OperatorKey>,
+    pub weights: Vec<Uint256>,
+    pub threshold: Uint256,
+    #[serde(default)]
+    pub nonce: Bytes32,
+}
+
+/// `ProofVerifier` selects how the gateway checks proofs, see `verifier::Verifier`.
+///
+/// Properties:
+///
+/// * `WeightedEcdsa`: A proof is a weighted operator set and the signatures of its operators, the
+///   operator set has to be within the key retention window.
+/// * `FixedKey`: A proof is a single signature of a fixed key, which always counts as the current
+///   operators. Meant for local testing only.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProofVerifier {
+    WeightedEcdsa,
+    FixedKey(OperatorKey),
+}
+
+/// `SigningDomain` selects what the operators sign for a given hash of signed data.
+///
+/// Properties:
+///
+/// * `Legacy`: The Ethereum personal-message hash of the data, which any deployment sharing the
+///   operators accepts.
+/// * `Transitional`: Either of the two, so relayers can switch over without downtime.
+/// * `Near`: `keccak256(domain_separator || hash)`, bound to this gateway account and chain.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum SigningDomain {
+    Legacy,
+    Transitional,
+    Near,
+}
+
+/// `ProposedOwner` is an ownership transfer waiting to be accepted.
+///
+/// Properties:
+///
+/// * `account_id`: The account that can accept ownership.
+/// * `expires_at`: The block timestamp the proposal expires at, in nanoseconds.
+#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposedOwner {
+    pub account_id: AccountId,
+    pub expires_at: u64,
+}
+
+/// `SignersRegistration` is the epoch and nonce an operator set was registered with.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignersRegistration {
+    pub epoch: u64,
+    pub nonce: Bytes32,
+}
+
+/// `SignatureReport` describes how a single signature of a proof was checked.
+///
+/// Properties:
+///
+/// * `signer`: The address recovered from the signature, or the ed25519 key it was verified
+///   against.
+/// * `operator_index`: The index of the operator the signer was matched to, if any.
+/// * `weight`: The weight the signature contributed, if it was matched.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignatureReport {
+    pub signer: Option<OperatorKey>,
+    pub operator_index: Option<u64>,
+    pub weight: Option<Uint256>,
+}
+
+/// `ProofReport` is the outcome of checking a proof, as returned by `inspect_proof`.
+///
+/// Properties:
+///
+/// * `epoch`: The epoch the proof's operator set was registered at, or `0` if it is unknown.
+/// * `is_current`: Whether the proof's operator set is the current one.
+/// * `signatures`: A report for each signature, in proof order.
+/// * `accumulated_weight`: The weight accumulated before the threshold was met or a check failed.
+/// * `threshold`: The threshold of the proof's operator set.
+/// * `failure`: The reason the first failing check gave, or `None` if the proof is valid.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProofReport {
+    pub epoch: u64,
+    pub is_current: bool,
+    pub signatures: Vec<SignatureReport>,
+    pub accumulated_weight: Uint256,
+    pub threshold: Uint256,
+    pub failure: Option<String>,
+}
+
+impl ProofReport {
+    /// It reports a proof that was rejected before any signature could be checked
+    ///
+    /// Arguments:
+    ///
+    /// * `failure`: The reason the proof was rejected.
+    ///
+    /// Returns:
+    ///
+    /// A report with no signatures and the failure.
+    pub(crate) fn rejected(failure: String) -> Self {
+        ProofReport {
+            epoch: 0,
+            is_current: false,
+            signatures: vec![],
+            accumulated_weight: Uint256::default(),
+            threshold: Uint256::default(),
+            failure: Some(failure),
+        }
+    }
+}
+
+/// `Proof` is a weighted multisig proof: the operator set that signed a message and the
+/// signatures of the signing operators, in the same order as the operators.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Proof {
+    pub signers: WeightedSigners,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+/// `ContractCallParams` are the parameters of an `approveContractCall` command.
+///
+/// Properties:
+///
+/// * `source_chain`: The chain that the contract call originated from.
+/// * `source_address`: The address of the sender on the source chain.
+/// * `contract_address`: The NEAR account the contract call is sent to.
+/// * `payload_hash`: The keccak256 hash of the payload.
+/// * `source_tx_hash`: The hash of the transaction on the source chain.
+/// * `source_event_index`: The index of the event in the transaction on the source chain.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractCallParams {
+    pub source_chain: String,
+    pub source_address: String,
+    pub contract_address: String,
+    pub payload_hash: [u8; 32],
+    pub source_tx_hash: [u8; 32],
+    pub source_event_index: u64,
+}
+
+/// `Message` is a cross-chain message approved through `approve_messages`.
+///
+/// Properties:
+///
+/// * `source_chain`: The chain that the message originated from.
+/// * `message_id`: The id of the message, unique for the source chain.
+/// * `source_address`: The address of the sender on the source chain.
+/// * `contract_address`: The NEAR account the message is sent to.
+/// * `payload_hash`: The keccak256 hash of the message payload.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Message {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub contract_address: String,
+    pub payload_hash: Bytes32,
+}
+
+impl ExecuteInput {
+    /// It decodes an `execute` input, detecting the format from its first byte
+    ///
+    /// Arguments:
+    ///
+    /// * `input`: Either the ABI encoding of `(bytes data, bytes proof)`, or
+    ///   `BORSH_ENCODING_VERSION` followed by a Borsh-encoded `ExecuteInput`.
+    ///
+    /// Returns:
+    ///
+    /// The decoded command batch, the hash the operators signed over, and the raw proof.
+    pub fn decode(input: &[u8]) -> Result<(CommandBatch, [u8; 32], Vec<u8>), String> {
+        match input.first() {
+            Some(0) => {
+                let tokens = abi_decode(input, &[ParamType::Bytes, ParamType::Bytes])?;
+
+                let data = tokens[0].clone().into_bytes().unwrap();
+                let proof = tokens[1].clone().into_bytes().unwrap();
+
+                Ok((CommandBatch::decode_abi(&data)?, keccak256(&data), proof))
+            }
+            Some(&BORSH_ENCODING_VERSION) => {
+                let input = Self::try_from_slice(&input[1..])
+                    .map_err(|e| format!("Error decoding Borsh-encoded input: {}", e))?;
+
+                let (chain_id, command_ids, commands, params) =
+                    BorshDeserialize::try_from_slice(&input.data)
+                        .map_err(|e| format!("Error decoding Borsh-encoded batch: {}", e))?;
+
+                let batch = CommandBatch {
+                    chain_id,
+                    command_ids,
+                    commands,
+                    params,
+                    encoding: BatchEncoding::Borsh,
+                };
+
+                let mut tagged_data = BORSH_BATCH_DOMAIN_TAG.to_vec();
+                tagged_data.extend_from_slice(&input.data);
+
+                Ok((batch, keccak256(tagged_data), input.proof))
+            }
+            _ => Err("Unsupported input encoding".to_string()),
+        }
+    }
+}
+
+impl CommandBatch {
+    /// It decodes the ABI encoding of `(uint256 chainId, bytes32[] commandIds, string[] commands,
+    /// bytes[] params)`
+    ///
+    /// Arguments:
+    ///
+    /// * `data`: The ABI-encoded batch.
+    ///
+    /// Returns:
+    ///
+    /// The decoded command batch.
+    pub fn decode_abi(data: &[u8]) -> Result<Self, String> {
+        let expected_output_types = vec![
+            ParamType::Uint(256),
+            ParamType::Array(Box::new(ParamType::FixedBytes(32))),
+            ParamType::Array(Box::new(ParamType::String)),
+            ParamType::Array(Box::new(ParamType::Bytes)),
+        ];
+
+        let data_tokens = abi_decode(data, &expected_output_types)?;
+
+        let chain_id = data_tokens[0].clone().into_uint().unwrap().as_u64();
+        let command_ids = data_tokens[1]
+            .clone()
+            .into_array()
+            .unwrap()
+            .into_iter()
+            .map(|token| token.into_fixed_bytes().unwrap().try_into().unwrap())
+            .collect::<Vec<_>>();
+
+        let commands = data_tokens[2]
+            .clone()
+            .into_array()
+            .unwrap()
+            .into_iter()
+            .map(|token| token.into_string().unwrap())
+            .collect::<Vec<_>>();
+
+        let params = data_tokens[3]
+            .clone()
+            .into_array()
+            .unwrap()
+            .into_iter()
+            .map(|token| token.into_bytes().unwrap())
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            chain_id,
+            command_ids,
+            commands,
+            params,
+            encoding: BatchEncoding::Abi,
+        })
+    }
+}
+
+impl WeightedSigners {
+    /// It decodes the operator set of a `transferOperatorship` command
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The params of the command, see `decode` for ABI batches.
+    /// * `encoding`: The format of the batch the command is in.
+    ///
+    /// Returns:
+    ///
+    /// The decoded operator set.
+    pub fn decode_params(params: &[u8], encoding: BatchEncoding) -> Result<Self, String> {
+        match encoding {
+            BatchEncoding::Abi => Self::decode(params),
+            BatchEncoding::Borsh => Self::try_from_slice(params)
+                .map_err(|e| format!("Error decoding Borsh-encoded operators: {}", e)),
+        }
+    }
+
+    /// It decodes an operator set, detecting the format from its first byte
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: Either the ABI encoding of `(address[] operators, uint256[] weights,
+    ///   uint256 threshold)`, which decodes with a zero nonce, or `BORSH_ENCODING_VERSION`
+    ///   followed by Borsh-encoded `WeightedSigners`.
+    ///
+    /// Returns:
+    ///
+    /// The decoded operator set.
+    pub fn decode(params: &[u8]) -> Result<Self, String> {
+        match params.first() {
+            Some(0) => {
+                let expected_output_types = vec![
+                    ParamType::Array(Box::new(ParamType::Address)),
+                    ParamType::Array(Box::new(ParamType::Uint(256))),
+                    ParamType::Uint(256),
+                ];
+
+                let tokens = abi_decode(params, &expected_output_types)?;
+
+                Ok(Self::from_tokens(&tokens))
+            }
+            Some(&BORSH_ENCODING_VERSION) => Self::try_from_slice(&params[1..])
+                .map_err(|e| format!("Error decoding Borsh-encoded operators: {}", e)),
+            _ => Err("Unsupported operators encoding".to_string()),
+        }
+    }
+
+    /// It sorts the operators in ascending order, keeping each weight with its operator, which is
+    /// the order the operator set is hashed and signed in
+    ///
+    /// Returns:
+    ///
+    /// The canonical operator set, or an error if the weights do not match the operators.
+    pub fn into_canonical(self) -> Result<Self, String> {
+        if self.weights.len() != self.operators.len() {
+            return Err("Invalid weights".to_string());
+        }
+
+        let mut pairs = self
+            .operators
+            .into_iter()
+            .zip(self.weights)
+            .collect::<Vec<_>>();
+        pairs.sort_by_key(|(operator, _)| *operator);
+
+        let (operators, weights) = pairs.into_iter().unzip();
+
+        Ok(Self {
+            operators,
+            weights,
+            ..self
+        })
+    }
+
+    /// It returns the hash the operator set is registered under
+    ///
+    /// Sets with a zero nonce hash as `keccak256(abi.encode(operators, weights, threshold))`, which
+    /// is the hash of the legacy ABI-encoded `transferOperatorship` params. Sets with a non-zero
+    /// nonce hash as `keccak256(abi.encode(operators, weights, threshold, nonce))`. See
+    /// `to_tokens` for how operators are encoded.
+    ///
+    /// Returns:
+    ///
+    /// A 32 byte array
+    pub fn hash(&self) -> [u8; 32] {
+        let mut tokens = self.to_tokens();
+
+        if self.nonce != Bytes32::default() {
+            tokens.push(Token::FixedBytes(self.nonce.0.to_vec()));
+        }
+
+        keccak256(abi_encode(tokens))
+    }
+
+    fn from_tokens(tokens: &[Token]) -> Self {
+        Self {
+            operators: tokens[0]
+                .clone()
+                .into_array()
+                .unwrap()
+                .into_iter()
+                .map(|token| OperatorKey::Secp256k1(EvmAddress(token.into_address().unwrap().0)))
+                .collect(),
+            weights: tokens[1]
+                .clone()
+                .into_array()
+                .unwrap()
+                .into_iter()
+                .map(|token| Uint256(token.into_uint().unwrap()))
+                .collect(),
+            threshold: Uint256(tokens[2].clone().into_uint().unwrap()),
+            nonce: Bytes32::default(),
+        }
+    }
+
+    /// It returns the ABI tokens of `(operators, weights, threshold)`
+    ///
+    /// Operators are encoded as `address[]` when they are all secp256k1 addresses, so such sets
+    /// keep the hash they have on other chains. Sets with ed25519 operators encode them as
+    /// `bytes[]` of tagged keys, see `OperatorKey::to_tagged_bytes`.
+    ///
+    /// Returns:
+    ///
+    /// The list of tokens.
+    pub(crate) fn to_tokens(&self) -> Vec<Token> {
+        let addresses = self
+            .operators
+            .iter()
+            .map(|operator| match operator {
+                OperatorKey::Secp256k1(address) => Some(Token::Address(address.0.into())),
+                OperatorKey::Ed25519(_) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let operators = addresses.unwrap_or_else(|| {
+            self.operators
+                .iter()
+                .map(|operator| Token::Bytes(operator.to_tagged_bytes()))
+                .collect()
+        });
+
+        vec![
+            Token::Array(operators),
+            Token::Array(
+                self.weights
+                    .iter()
+                    .map(|weight| Token::Uint(weight.0))
+                    .collect(),
+            ),
+            Token::Uint(self.threshold.0),
+        ]
+    }
+}
+
+impl Proof {
+    /// It decodes a proof, detecting the format from its first byte
+    ///
+    /// Arguments:
+    ///
+    /// * `proof`: Either the ABI encoding of `(address[] operators, uint256[] weights,
+    ///   uint256 threshold, bytes[] signatures)`, or `BORSH_ENCODING_VERSION` followed by a
+    ///   Borsh-encoded `Proof`.
+    ///
+    /// Returns:
+    ///
+    /// The decoded proof.
+    pub fn decode(proof: &[u8]) -> Result<Self, String> {
+        match proof.first() {
+            Some(0) => {
+                let expected_output_types = vec![
+                    ParamType::Array(Box::new(ParamType::Address)),
+                    ParamType::Array(Box::new(ParamType::Uint(256))),
+                    ParamType::Uint(256),
+                    ParamType::Array(Box::new(ParamType::Bytes)),
+                ];
+
+                let tokens = abi_decode(proof, &expected_output_types)?;
+
+                Ok(Self {
+                    signers: WeightedSigners::from_tokens(&tokens),
+                    signatures: tokens[3]
+                        .clone()
+                        .into_array()
+                        .unwrap()
+                        .into_iter()
+                        .map(|token| token.into_bytes().unwrap())
+                        .collect(),
+                })
+            }
+            Some(&BORSH_ENCODING_VERSION) => Self::try_from_slice(&proof[1..])
+                .map_err(|e| format!("Error decoding Borsh-encoded proof: {}", e)),
+            _ => Err("Unsupported proof encoding".to_string()),
+        }
+    }
+}
+
+impl ContractCallParams {
+    /// It decodes the params of an `approveContractCall` command
+    ///
+    /// Arguments:
+    ///
+    /// * `params`: The ABI encoding of `(string sourceChain, string sourceAddress,
+    ///   string contractAddress, bytes32 payloadHash, bytes32 sourceTxHash,
+    ///   uint256 sourceEventIndex)`, or Borsh-encoded `ContractCallParams` in a Borsh batch.
+    /// * `encoding`: The format of the batch the command is in.
+    ///
+    /// Returns:
+    ///
+    /// The decoded params.
+    pub fn decode(params: &[u8], encoding: BatchEncoding) -> Result<Self, String> {
+        match encoding {
+            BatchEncoding::Abi => {
+                let expected_output_types = vec![
+                    ParamType::String,
+                    ParamType::String,
+                    ParamType::String,
+                    ParamType::FixedBytes(32),
+                    ParamType::FixedBytes(32),
+                    ParamType::Uint(256),
+                ];
+
+                let tokens = abi_decode(params, &expected_output_types)?;
+
+                Ok(Self {
+                    source_chain: tokens[0].clone().into_string().unwrap(),
+                    source_address: tokens[1].clone().into_string().unwrap(),
+                    contract_address: tokens[2].clone().into_string().unwrap(),
+                    payload_hash: tokens[3]
+                        .clone()
+                        .into_fixed_bytes()
+                        .unwrap()
+                        .try_into()
+                        .unwrap(),
+                    source_tx_hash: tokens[4]
+                        .clone()
+                        .into_fixed_bytes()
+                        .unwrap()
+                        .try_into()
+                        .unwrap(),
+                    source_event_index: tokens[5].clone().into_uint().unwrap().as_u64(), // Crashes
+                })
+            }
+            BatchEncoding::Borsh => Self::try_from_slice(params)
+                .map_err(|e| format!("Error decoding Borsh-encoded params: {}", e)),
+        }
+    }
+}
+
+impl Message {
+    /// It returns the command id a message is tracked under, the keccak256 hash of
+    /// `"{source_chain}_{message_id}"`
+    ///
+    /// Returns:
+    ///
+    /// A 32 byte array
+    pub fn command_id(source_chain: &str, message_id: &str) -> [u8; 32] {
+        keccak256(format!("{}_{}", source_chain, message_id))
+    }
+
+    pub(crate) fn to_token(&self) -> Token {
+        Token::Tuple(vec![
+            Token::String(self.source_chain.clone()),
+            Token::String(self.message_id.clone()),
+            Token::String(self.source_address.clone()),
+            Token::String(self.contract_address.clone()),
+            Token::FixedBytes(self.payload_hash.0.to_vec()),
+        ])
+    }
+}
@@ -8,39 +8,165 @@ use ethabi::ethereum_types::H256;
 use ethabi::Address;
 use ethabi::ParamType;
 use ethabi::Token;
+use near_sdk::env;
 use sha3::{Digest, Keccak256};
+use std::fmt;
 use uint::hex;
 
-/// It takes a hash and a signature, and returns the address that signed the hash
+/// Half of the secp256k1 curve order, the largest `s` value a non-malleable signature can have.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// `SignatureError` is the reason an operator signature was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signature is not exactly 65 bytes long.
+    InvalidLength,
+    /// The recovery byte is not one of 0, 1, 27 or 28.
+    InvalidRecoveryId,
+    /// The `s` value is in the upper half of the curve order, i.e. a malleated signature.
+    HighS,
+    /// No public key could be recovered from the signature.
+    RecoveryFailed,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            SignatureError::InvalidLength => "Invalid signature length",
+            SignatureError::InvalidRecoveryId => "Invalid signature recovery id",
+            SignatureError::HighS => "Invalid signature s value",
+            SignatureError::RecoveryFailed => "Invalid signature",
+        };
+
+        f.write_str(reason)
+    }
+}
+
+/// It takes a hash and a signature, checks that the signature is in its canonical form, and returns
+/// the address that signed the hash, using the `ecrecover` host function
 ///
 /// Arguments:
 ///
 /// * `hash`: The hash of the message to be signed.
-/// * `signature`: The signature to verify.
+/// * `signature`: The 65-byte `r || s || v` signature to verify, with `v` either 0/1 or 27/28 and a
+///   low `s` value.
 ///
 /// Returns:
 ///
-/// The address of the signer.
-pub fn ecrecover(hash: H256, signature: &[u8]) -> Result<Address, ()> {
-    assert_eq!(signature.len(), 65);
+/// The address of the signer, or the reason the signature was rejected.
+pub fn ecrecover(hash: H256, signature: &[u8]) -> Result<Address, SignatureError> {
+    if signature.len() != 65 {
+        return Err(SignatureError::InvalidLength);
+    }
 
-    let hash = secp256k1::Message::parse_slice(hash.as_bytes()).unwrap();
-    let v = signature[64];
-    let signature = secp256k1::Signature::parse_slice(&signature[0..64]).unwrap();
-    let bit = match v {
-        0..=26 => v,
-        _ => v - 27,
+    let v = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        _ => return Err(SignatureError::InvalidRecoveryId),
     };
 
-    if let Ok(recovery_id) = secp256k1::RecoveryId::parse(bit) {
-        if let Ok(public_key) = secp256k1::recover(&hash, &signature, &recovery_id) {
-            // recover returns a 65-byte key, but addresses come from the raw 64-byte key
-            let r = sha3::Keccak256::digest(&public_key.serialize()[1..]);
-            return Ok(Address::from_slice(&r[12..]));
-        }
+    // Big-endian byte arrays of equal length compare like the numbers they encode
+    if signature[32..64] > SECP256K1_HALF_ORDER[..] {
+        return Err(SignatureError::HighS);
     }
 
-    Err(())
+    let public_key = env::ecrecover(hash.as_bytes(), &signature[0..64], v, false)
+        .ok_or(SignatureError::RecoveryFailed)?;
+
+    // ecrecover returns the raw 64-byte key, which is what addresses are derived from
+    let r = keccak256(public_key);
+
+    Ok(Address::from_slice(&r[12..]))
+}
+
+#[cfg(target_arch = "wasm32")]
+mod host {
+    // near-sdk 4.1 does not wrap this host function yet
+    extern "C" {
+        pub fn ed25519_verify(
+            signature_len: u64,
+            signature_ptr: u64,
+            message_len: u64,
+            message_ptr: u64,
+            public_key_len: u64,
+            public_key_ptr: u64,
+        ) -> u64;
+    }
+}
+
+/// It verifies an ed25519 signature over a message, using the `ed25519_verify` host function
+///
+/// Arguments:
+///
+/// * `signature`: The 64-byte signature to verify.
+/// * `message`: The message that was signed.
+/// * `public_key`: The 32-byte public key of the signer.
+///
+/// Returns:
+///
+/// `true` if the signature is valid.
+#[cfg(target_arch = "wasm32")]
+pub fn ed25519_verify(signature: &[u8; 64], message: &[u8], public_key: &[u8; 32]) -> bool {
+    unsafe {
+        host::ed25519_verify(
+            signature.len() as _,
+            signature.as_ptr() as _,
+            message.len() as _,
+            message.as_ptr() as _,
+            public_key.len() as _,
+            public_key.as_ptr() as _,
+        ) == 1
+    }
+}
+
+/// Native builds have no host to call into, so they verify in-process instead.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn ed25519_verify(signature: &[u8; 64], message: &[u8], public_key: &[u8; 32]) -> bool {
+    use ed25519_dalek::Verifier;
+
+    let signature = ed25519_dalek::Signature::from(*signature);
+
+    ed25519_dalek::PublicKey::from_bytes(public_key)
+        .map(|public_key| public_key.verify(message, &signature).is_ok())
+        .unwrap_or(false)
+}
+
+/// It takes a 32-byte hash and returns the hash of it prefixed with the Ethereum personal message
+/// prefix, which is what operators sign
+///
+/// Arguments:
+///
+/// * `hash`: The hash of the signed data.
+///
+/// Returns:
+///
+/// A 32 byte array
+pub fn to_eth_signed_message_hash(hash: [u8; 32]) -> [u8; 32] {
+    const PREFIX: &str = "\x19Ethereum Signed Message:\n32";
+    let mut eth_message = PREFIX.as_bytes().to_vec();
+    eth_message.extend_from_slice(hash.as_ref());
+
+    keccak256(eth_message)
+}
+
+/// It takes a 32-byte hash and returns the hash of it bound to a signing domain
+///
+/// Arguments:
+///
+/// * `domain_separator`: The domain separator of the gateway.
+/// * `hash`: The hash of the signed data.
+///
+/// Returns:
+///
+/// A 32 byte array
+pub fn to_domain_signed_message_hash(domain_separator: [u8; 32], hash: [u8; 32]) -> [u8; 32] {
+    let mut message = domain_separator.to_vec();
+    message.extend_from_slice(hash.as_ref());
+
+    keccak256(message)
 }
 
 /// It takes a slice of bytes and returns a 32-byte hash
@@ -97,36 +223,6 @@ pub fn abi_encode(tokens: Vec<Token>) -> Vec<u8> {
     encode(&tokens)
 }
 
-/// It takes a string, removes the first two characters, and then converts the remaining string into a
-/// vector of bytes
-///
-/// Arguments:
-///
-/// * `payload`: The payload of the transaction.
-///
-/// Returns:
-///
-/// A vector of bytes
-pub fn clean_payload(payload: String) -> Vec<u8> {
-    let clean_payload = &payload[2..payload.len()];
-    hex::decode(clean_payload).unwrap()
-}
-
-/// It takes a string, removes the first two characters, and then converts the remaining string into a
-/// 256-bit hash
-///
-/// Arguments:
-///
-/// * `payload`: The payload of the transaction.
-///
-/// Returns:
-///
-/// A H256 hash
-pub fn to_h256(payload: String) -> H256 {
-    let clean_payload = &payload[2..payload.len()];
-    <H256 as std::str::FromStr>::from_str(clean_payload).unwrap()
-}
-
 /// It takes a 32-byte array and returns a hex string
 ///
 /// Arguments:
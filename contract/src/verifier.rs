@@ -0,0 +1,127 @@
+/*
+ * Axelar Proof Verifiers
+ *
+ */
+use crate::auth_weighted::ED25519_SIGNATURE_LENGTH;
+use crate::types::{
+    EvmAddress, OperatorKey, Proof, ProofReport, ProofVerifier, SignatureReport, Uint256,
+};
+use crate::utils;
+use crate::Axelar;
+use ethabi::ethereum_types::{H256, U256};
+
+/// `Verifier` checks a proof over the hash of a signed message.
+///
+/// New proof systems implement it and get a variant in `ProofVerifier`, the gateway entrypoints
+/// only ever hand it the raw proof bytes.
+pub(crate) trait Verifier {
+    /// It checks a proof over a message hash and reports the outcome of every check
+    ///
+    /// Arguments:
+    ///
+    /// * `gateway`: The gateway state, for verifiers that depend on the registered operator sets.
+    /// * `message_hash`: The hash of the message that was signed.
+    /// * `proof`: The encoded proof.
+    ///
+    /// Returns:
+    ///
+    /// A report with the first failure, if any.
+    fn inspect(&self, gateway: &Axelar, message_hash: [u8; 32], proof: &[u8]) -> ProofReport;
+
+    /// It checks a proof over a message hash
+    ///
+    /// Arguments:
+    ///
+    /// * `gateway`: The gateway state, for verifiers that depend on the registered operator sets.
+    /// * `message_hash`: The hash of the message that was signed.
+    /// * `proof`: The encoded proof.
+    ///
+    /// Returns:
+    ///
+    /// Whether the proof is from the current operators, or the reason it was rejected.
+    fn verify(
+        &self,
+        gateway: &Axelar,
+        message_hash: [u8; 32],
+        proof: &[u8],
+    ) -> Result<bool, String> {
+        let report = self.inspect(gateway, message_hash, proof);
+
+        match report.failure {
+            None => Ok(report.is_current),
+            Some(failure) => Err(failure),
+        }
+    }
+}
+
+/// `WeightedEcdsaVerifier` checks weighted multisig proofs against the operator sets of the
+/// gateway.
+pub(crate) struct WeightedEcdsaVerifier;
+
+impl Verifier for WeightedEcdsaVerifier {
+    fn inspect(&self, gateway: &Axelar, message_hash: [u8; 32], proof: &[u8]) -> ProofReport {
+        match Proof::decode(proof) {
+            Ok(proof) => gateway.internal_inspect_proof(message_hash, &proof),
+            Err(e) => ProofReport::rejected(e),
+        }
+    }
+}
+
+/// `FixedKeyVerifier` accepts a single signature of a fixed secp256k1 or ed25519 key as the proof.
+pub(crate) struct FixedKeyVerifier {
+    pub key: OperatorKey,
+}
+
+impl Verifier for FixedKeyVerifier {
+    fn inspect(&self, _gateway: &Axelar, message_hash: [u8; 32], proof: &[u8]) -> ProofReport {
+        let signer = match self.key {
+            OperatorKey::Secp256k1(_) => match utils::ecrecover(H256(message_hash), proof) {
+                Ok(signer) => OperatorKey::Secp256k1(EvmAddress(signer.0)),
+                Err(e) => return ProofReport::rejected(e.to_string()),
+            },
+            OperatorKey::Ed25519(public_key) => {
+                let signature: &[u8; ED25519_SIGNATURE_LENGTH] = match proof.try_into() {
+                    Ok(signature) => signature,
+                    Err(_) => return ProofReport::rejected("Invalid signature length".to_string()),
+                };
+
+                if !utils::ed25519_verify(signature, &message_hash, &public_key) {
+                    return ProofReport::rejected("Invalid signer".to_string());
+                }
+
+                self.key
+            }
+        };
+
+        let is_signed_by_key = signer == self.key;
+        let weight = Uint256(U256::from(is_signed_by_key as u8));
+
+        // The key is the only operator, with a weight and threshold of one
+        ProofReport {
+            epoch: 0,
+            is_current: is_signed_by_key,
+            signatures: vec![SignatureReport {
+                signer: Some(signer),
+                operator_index: is_signed_by_key.then_some(0),
+                weight: is_signed_by_key.then_some(weight),
+            }],
+            accumulated_weight: weight,
+            threshold: Uint256(U256::one()),
+            failure: (!is_signed_by_key).then(|| "Invalid signer".to_string()),
+        }
+    }
+}
+
+impl ProofVerifier {
+    /// It returns the verifier of the proof system
+    ///
+    /// Returns:
+    ///
+    /// A boxed `Verifier`.
+    pub(crate) fn verifier(self) -> Box<dyn Verifier> {
+        match self {
+            ProofVerifier::WeightedEcdsa => Box::new(WeightedEcdsaVerifier),
+            ProofVerifier::FixedKey(key) => Box::new(FixedKeyVerifier { key }),
+        }
+    }
+}
@@ -53,13 +53,13 @@ impl AxelarNearExample {
     pub fn set(&mut self, chain: String, destination_address: String, value: String) -> Promise {
         self.value = Some(value.clone());
         let payload = abi_encode(vec![Token::String(value)]);
-        self.gateway_call_contract(chain, destination_address, payload)
+        self.gateway_call_contract(chain, destination_address, payload.parse().unwrap())
     }
 }
 
 impl ContractExecutable for AxelarNearExample {
     fn _execute(&mut self, source_chain: String, source_address: String, payload: Vec<u8>) {
-        let tokens = abi_decode(&payload, &vec![ParamType::String]).unwrap();
+        let tokens = abi_decode(&payload, &[ParamType::String]).unwrap();
 
         self.value = tokens[0].clone().into_string();
         self.source_chain = Some(source_chain);
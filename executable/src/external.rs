@@ -1,3 +1,4 @@
+use crate::types::{Bytes, Bytes32};
 use near_sdk::ext_contract;
 
 /// A trait that defines the functions that the gateway contract will have.
@@ -5,15 +6,15 @@ use near_sdk::ext_contract;
 pub trait Gateway {
     fn validate_contract_call(
         &mut self,
-        command_id: String,
+        command_id: Bytes32,
         source_chain: String,
         source_address: String,
-        payload_hash: String,
+        payload_hash: Bytes32,
     ) -> bool;
 
     fn call_contract(
         destination_chain: String,
         destination_contract_address: String,
-        payload: String,
+        payload: Bytes,
     );
 }
@@ -1,8 +1,10 @@
 pub mod external;
+pub mod types;
 pub mod utils;
 pub extern crate ethabi;
 
 pub use near_sdk::{Promise, PromiseError};
+pub use types::{Bytes, Bytes32};
 
 /// This is a trait that is implemented by the contract and provides a contract-specific way to execute a command.
 pub trait ContractExecutable {
@@ -15,22 +17,22 @@ pub trait AxelarExecutable {
         &self,
         destination_chain: String,
         destination_contract_address: String,
-        payload: String,
+        payload: Bytes,
     ) -> Promise;
 
     fn execute(
         &mut self,
-        command_id: String,
+        command_id: Bytes32,
         source_chain: String,
         source_address: String,
-        payload: String,
+        payload: Bytes,
     ) -> Promise;
 
     fn execute_callback(
         &mut self,
         source_chain: String,
         source_address: String,
-        payload: String,
+        payload: Bytes,
         call_result: Result<bool, PromiseError>,
     );
 }
@@ -41,6 +43,7 @@ macro_rules! impl_axelar_executable {
     ($contract: ident, $gateway_account_id: ident, $_execute: ident) => {
         use near_sdk::*;
         use $crate::external::*;
+        use $crate::types::*;
         use $crate::utils::*;
 
         pub const TGAS: u64 = 1_000_000_000_000;
@@ -51,7 +54,7 @@ macro_rules! impl_axelar_executable {
                 &self,
                 destination_chain: String,
                 destination_contract_address: String,
-                payload: String,
+                payload: Bytes,
             ) -> Promise {
                 axelar_gateway::ext(self.$gateway_account_id.clone())
                     .with_static_gas(Gas(5 * TGAS))
@@ -61,22 +64,21 @@ macro_rules! impl_axelar_executable {
             #[payable]
             fn execute(
                 &mut self,
-                command_id: String,
+                command_id: Bytes32,
                 source_chain: String,
                 source_address: String,
-                payload: String,
+                payload: Bytes,
             ) -> Promise {
-                let keccak_payload = keccak256(clean_payload(payload.clone()));
-                let payload_hash = to_eth_hex_string(keccak_payload);
+                let payload_hash = Bytes32(keccak256(&payload.0));
 
                 axelar_gateway::ext(self.$gateway_account_id.clone())
                     .with_static_gas(Gas(5 * TGAS))
                     .with_attached_deposit(0)
                     .validate_contract_call(
-                        command_id.clone(),
+                        command_id,
                         source_chain.clone(),
                         source_address.clone(),
-                        payload_hash.clone(),
+                        payload_hash,
                     )
                     .then(
                         Self::ext(env::current_account_id())
@@ -94,7 +96,7 @@ macro_rules! impl_axelar_executable {
                 &mut self,
                 source_chain: String,
                 source_address: String,
-                payload: String,
+                payload: Bytes,
                 #[callback_result] call_result: Result<bool, near_sdk::PromiseError>,
             ) {
                 if call_result.is_err() {
@@ -106,9 +108,7 @@ macro_rules! impl_axelar_executable {
                     env::panic_str("Not approved by gateway");
                 }
 
-                let clean_payload = clean_payload(payload.clone());
-
-                self.$_execute(source_chain, source_address, clean_payload);
+                self.$_execute(source_chain, source_address, payload.0);
             }
         }
     };
@@ -0,0 +1,141 @@
+/*
+ * Axelar argument types
+ *
+ */
+use near_sdk::base64;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::de;
+use near_sdk::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use uint::hex;
+
+/// `Bytes` is a byte payload passed to the contract as a JSON string.
+///
+/// It accepts either a `0x`-prefixed hex string or a padded Base64 string (the `Base64VecU8`
+/// encoding), and always serializes as a `0x`-prefixed hex string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bytes(pub Vec<u8>);
+
+/// `Bytes32` is a 32-byte id or hash passed to the contract as a JSON string.
+///
+/// It accepts a `0x`-prefixed hex string or a padded Base64 string, like `Bytes`, and always
+/// serializes as a `0x`-prefixed hex string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct Bytes32(pub [u8; 32]);
+
+/// It takes a `0x`-prefixed hex string or a padded Base64 string and returns the decoded bytes
+///
+/// Hex without its `0x` prefix is rejected instead of being read as Base64, and so is Base64 that
+/// is not in its canonical, padded form.
+///
+/// Arguments:
+///
+/// * `value`: The encoded string.
+///
+/// Returns:
+///
+/// A vector of bytes, or an error message if the string is neither valid hex nor valid Base64.
+pub fn decode_hex_or_base64(value: &str) -> Result<Vec<u8>, String> {
+    if let Some(hex_value) = value.strip_prefix("0x") {
+        return hex::decode(hex_value).map_err(|e| format!("Invalid hex string: {}", e));
+    }
+
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("Invalid hex string: missing 0x prefix".to_string());
+    }
+
+    let bytes = base64::decode(value).map_err(|e| format!("Invalid base64 string: {}", e))?;
+
+    // Unpadded Base64 decodes as well, only the padded encoding of the bytes round-trips
+    if base64::encode(&bytes) != value {
+        return Err("Invalid base64 string: not padded".to_string());
+    }
+
+    Ok(bytes)
+}
+
+impl FromStr for Bytes {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        decode_hex_or_base64(value).map(Self)
+    }
+}
+
+impl FromStr for Bytes32 {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_hex_or_base64(value)?;
+
+        let length = bytes.len();
+
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| format!("Expected 32 bytes, got {}", length))
+    }
+}
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(&self.0))
+    }
+}
+
+impl fmt::Display for Bytes32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl From<[u8; 32]> for Bytes32 {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Bytes32> for [u8; 32] {
+    fn from(bytes: Bytes32) -> Self {
+        bytes.0
+    }
+}
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl Serialize for Bytes32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
@@ -9,6 +9,8 @@ use ethabi::Token;
 use sha3::{Digest, Keccak256};
 use uint::hex;
 
+use crate::types::decode_hex_or_base64;
+
 /// It takes a slice of bytes and returns a 32-byte hash
 /// Compute the Keccak-256 hash of input bytes.
 ///
@@ -65,8 +67,7 @@ pub fn abi_encode(tokens: Vec<Token>) -> String {
     format!("0x{}", hex::encode::<Vec<u8>>(payload))
 }
 
-/// It takes a string, removes the first two characters, and then converts the remaining string into a
-/// vector of bytes
+/// It takes a `0x`-prefixed hex string or a Base64 string and converts it into a vector of bytes
 ///
 /// Arguments:
 ///
@@ -76,8 +77,7 @@ pub fn abi_encode(tokens: Vec<Token>) -> String {
 ///
 /// A vector of bytes
 pub fn clean_payload(payload: String) -> Vec<u8> {
-    let clean_payload = &payload[2..payload.len()];
-    hex::decode(clean_payload).unwrap()
+    decode_hex_or_base64(&payload).unwrap()
 }
 
 /// It takes a 32-byte array and returns a hex string
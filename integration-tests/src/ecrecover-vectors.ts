@@ -0,0 +1,622 @@
+// Signatures recovered by the `ecrecover` of gateway 1.0.0, built on libsecp256k1 0.3.5,
+// and by the current `ecrecover`, built on the `ecrecover` host function, side by side.
+//
+// Each key is keccak256 of its seed, the hashes are keccak256("key <k> message <i>") for the
+// first messages signed with each recovery id. Every signature is included with both
+// encodings of its recovery id and as its high-s twin, n - s with the other recovery id.
+//
+// * `legacy`: The signer libsecp256k1 recovered, "rejected" when it returned an error and
+//   "panicked" when it aborted the call.
+// * `signer`: The signer the gateway recovers now, or `null` if it rejects the signature.
+// * `failure`: The reason the gateway rejects the signature, or `null` if it recovers it.
+
+export const ECRECOVER_SIGNERS: { seed: string; address: string }[] = [
+  {
+    seed: "axelar-near ecrecover vectors 0",
+    address: "0xe2067005229dc01341b482432016667b14ad7942",
+  },
+  {
+    seed: "axelar-near ecrecover vectors 1",
+    address: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+  },
+  {
+    seed: "axelar-near ecrecover vectors 2",
+    address: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+  },
+];
+
+export type EcrecoverVector = {
+  name: string;
+  hash: string;
+  signature: string;
+  legacy: string;
+  signer: string | null;
+  failure: string | null;
+};
+
+export const ECRECOVER_VECTORS: EcrecoverVector[] = [
+  {
+    name: "key 0, message 0 with recovery id 0, v = 27",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba1601b",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 0 with recovery id 0, v = 0",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16000",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 0 with recovery id 0, high s, v = 28",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a35b2c4121299a06fe2305d3bcee6ada8d764dadecd6b3791aa3857e81b98ca9fe11c",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 0 with recovery id 0, high s, v = 1",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a35b2c4121299a06fe2305d3bcee6ada8d764dadecd6b3791aa3857e81b98ca9fe101",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 1 with recovery id 0, v = 27",
+    hash: "0xb35aed55b17c28805df5dd5491a063cb2244546b298d9f2c9efb9b4b2a77362c",
+    signature:
+      "0x6731fffd62144a8efe285a46c50288912ff944a10244e509881112b66e776b6a098575c2e3293ab35d72aafe13533e6e990b771ec56602577ef02992c104fac01b",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 1 with recovery id 0, v = 0",
+    hash: "0xb35aed55b17c28805df5dd5491a063cb2244546b298d9f2c9efb9b4b2a77362c",
+    signature:
+      "0x6731fffd62144a8efe285a46c50288912ff944a10244e509881112b66e776b6a098575c2e3293ab35d72aafe13533e6e990b771ec56602577ef02992c104fac000",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 1 with recovery id 0, high s, v = 28",
+    hash: "0xb35aed55b17c28805df5dd5491a063cb2244546b298d9f2c9efb9b4b2a77362c",
+    signature:
+      "0x6731fffd62144a8efe285a46c50288912ff944a10244e509881112b66e776b6af67a8a3d1cd6c54ca28d5501ecacc19021a365c7e9e29de440e234fa0f3146811c",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 1 with recovery id 0, high s, v = 1",
+    hash: "0xb35aed55b17c28805df5dd5491a063cb2244546b298d9f2c9efb9b4b2a77362c",
+    signature:
+      "0x6731fffd62144a8efe285a46c50288912ff944a10244e509881112b66e776b6af67a8a3d1cd6c54ca28d5501ecacc19021a365c7e9e29de440e234fa0f31468101",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 0 with recovery id 1, v = 28",
+    hash: "0xe27edec26bc229767976846f41adb76f06adc5b0c7ff21b127b520dcebf5587d",
+    signature:
+      "0x5b6eb1653342e13a98f1fed0250dad3f07fc4f8bc050ebce883d982bf7c56d3b3cc1f1be6655a715dc54642ef523a2f444e801787f46d3339a9f8e4eb38cb84f1c",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 0 with recovery id 1, v = 1",
+    hash: "0xe27edec26bc229767976846f41adb76f06adc5b0c7ff21b127b520dcebf5587d",
+    signature:
+      "0x5b6eb1653342e13a98f1fed0250dad3f07fc4f8bc050ebce883d982bf7c56d3b3cc1f1be6655a715dc54642ef523a2f444e801787f46d3339a9f8e4eb38cb84f01",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 0 with recovery id 1, high s, v = 27",
+    hash: "0xe27edec26bc229767976846f41adb76f06adc5b0c7ff21b127b520dcebf5587d",
+    signature:
+      "0x5b6eb1653342e13a98f1fed0250dad3f07fc4f8bc050ebce883d982bf7c56d3bc33e0e4199aa58ea23ab9bd10adc5d0a75c6db6e3001cd082532d03e1ca988f21b",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 0 with recovery id 1, high s, v = 0",
+    hash: "0xe27edec26bc229767976846f41adb76f06adc5b0c7ff21b127b520dcebf5587d",
+    signature:
+      "0x5b6eb1653342e13a98f1fed0250dad3f07fc4f8bc050ebce883d982bf7c56d3bc33e0e4199aa58ea23ab9bd10adc5d0a75c6db6e3001cd082532d03e1ca988f200",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 1 with recovery id 1, v = 28",
+    hash: "0xe8b5eebacdf9e3cd051427923640717d6ed77f5efe6395650f48801f4b7de511",
+    signature:
+      "0xbe96b31443f0efc5d44b42a91889d5f811bc3ad4246693656f8dd30fb875833720053b0a64142d6b91f61ed0d725f82875e13952b322f690fe9ea85e812cfc121c",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 1 with recovery id 1, v = 1",
+    hash: "0xe8b5eebacdf9e3cd051427923640717d6ed77f5efe6395650f48801f4b7de511",
+    signature:
+      "0xbe96b31443f0efc5d44b42a91889d5f811bc3ad4246693656f8dd30fb875833720053b0a64142d6b91f61ed0d725f82875e13952b322f690fe9ea85e812cfc1201",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: "0xe2067005229dc01341b482432016667b14ad7942",
+    failure: null,
+  },
+  {
+    name: "key 0, message 1 with recovery id 1, high s, v = 27",
+    hash: "0xe8b5eebacdf9e3cd051427923640717d6ed77f5efe6395650f48801f4b7de511",
+    signature:
+      "0xbe96b31443f0efc5d44b42a91889d5f811bc3ad4246693656f8dd30fb8758337dffac4f59bebd2946e09e12f28da07d644cda393fc25a9aac133b62e4f09452f1b",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, message 1 with recovery id 1, high s, v = 0",
+    hash: "0xe8b5eebacdf9e3cd051427923640717d6ed77f5efe6395650f48801f4b7de511",
+    signature:
+      "0xbe96b31443f0efc5d44b42a91889d5f811bc3ad4246693656f8dd30fb8758337dffac4f59bebd2946e09e12f28da07d644cda393fc25a9aac133b62e4f09452f00",
+    legacy: "0xe2067005229dc01341b482432016667b14ad7942",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 0 with recovery id 0, v = 27",
+    hash: "0x73282c537b62be98039939790c1b1d96653ef5d1cb4e71b499327f17f2fdb9f8",
+    signature:
+      "0x4d3ddaf8653f2f8c1111bdd41fb54b5f0c04397ec9a7f8a2028f0ea132b49df14680d945a1aff2a02bd64ffc529fbebf48d39467362ad6ec08f1511140b69abe1b",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 0 with recovery id 0, v = 0",
+    hash: "0x73282c537b62be98039939790c1b1d96653ef5d1cb4e71b499327f17f2fdb9f8",
+    signature:
+      "0x4d3ddaf8653f2f8c1111bdd41fb54b5f0c04397ec9a7f8a2028f0ea132b49df14680d945a1aff2a02bd64ffc529fbebf48d39467362ad6ec08f1511140b69abe00",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 0 with recovery id 0, high s, v = 28",
+    hash: "0x73282c537b62be98039939790c1b1d96653ef5d1cb4e71b499327f17f2fdb9f8",
+    signature:
+      "0x4d3ddaf8653f2f8c1111bdd41fb54b5f0c04397ec9a7f8a2028f0ea132b49df1b97f26ba5e500d5fd429b003ad60413f71db487f791dc94fb6e10d7b8f7fa6831c",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 0 with recovery id 0, high s, v = 1",
+    hash: "0x73282c537b62be98039939790c1b1d96653ef5d1cb4e71b499327f17f2fdb9f8",
+    signature:
+      "0x4d3ddaf8653f2f8c1111bdd41fb54b5f0c04397ec9a7f8a2028f0ea132b49df1b97f26ba5e500d5fd429b003ad60413f71db487f791dc94fb6e10d7b8f7fa68301",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 1 with recovery id 0, v = 27",
+    hash: "0x82eab8bdaa959e367a327523009321ba9af0451fea17a98b2a8dd82427e13848",
+    signature:
+      "0x6ad918d3d9e8a5056d96e123f46a7aeb049321b4f382bd373dfc0cd7a53200e867b9c1191cdb9a9b373f4b07e2ae650af8365912a1eb59cbc465aecd1e5f1bbf1b",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 1 with recovery id 0, v = 0",
+    hash: "0x82eab8bdaa959e367a327523009321ba9af0451fea17a98b2a8dd82427e13848",
+    signature:
+      "0x6ad918d3d9e8a5056d96e123f46a7aeb049321b4f382bd373dfc0cd7a53200e867b9c1191cdb9a9b373f4b07e2ae650af8365912a1eb59cbc465aecd1e5f1bbf00",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 1 with recovery id 0, high s, v = 28",
+    hash: "0x82eab8bdaa959e367a327523009321ba9af0451fea17a98b2a8dd82427e13848",
+    signature:
+      "0x6ad918d3d9e8a5056d96e123f46a7aeb049321b4f382bd373dfc0cd7a53200e898463ee6e3246564c8c0b4f81d519af3c27883d40d5d466ffb6cafbfb1d725821c",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 1 with recovery id 0, high s, v = 1",
+    hash: "0x82eab8bdaa959e367a327523009321ba9af0451fea17a98b2a8dd82427e13848",
+    signature:
+      "0x6ad918d3d9e8a5056d96e123f46a7aeb049321b4f382bd373dfc0cd7a53200e898463ee6e3246564c8c0b4f81d519af3c27883d40d5d466ffb6cafbfb1d7258201",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 0 with recovery id 1, v = 28",
+    hash: "0x8d4be0220a22df2e7160d0faf3f7e8027cb7f6834f2187c5ef0b2be6354e281e",
+    signature:
+      "0x67eaefab6ec390d1256087b97bf74d592847c3df24b63afa85eb2f83468e59813e55c1c32039aa93bda18766b801ff0520171bb51f14ebf732b4c0faa24f9ab81c",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 0 with recovery id 1, v = 1",
+    hash: "0x8d4be0220a22df2e7160d0faf3f7e8027cb7f6834f2187c5ef0b2be6354e281e",
+    signature:
+      "0x67eaefab6ec390d1256087b97bf74d592847c3df24b63afa85eb2f83468e59813e55c1c32039aa93bda18766b801ff0520171bb51f14ebf732b4c0faa24f9ab801",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 0 with recovery id 1, high s, v = 27",
+    hash: "0x8d4be0220a22df2e7160d0faf3f7e8027cb7f6834f2187c5ef0b2be6354e281e",
+    signature:
+      "0x67eaefab6ec390d1256087b97bf74d592847c3df24b63afa85eb2f83468e5981c1aa3e3cdfc6556c425e789947fe00f99a97c1319033b4448d1d9d922de6a6891b",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 0 with recovery id 1, high s, v = 0",
+    hash: "0x8d4be0220a22df2e7160d0faf3f7e8027cb7f6834f2187c5ef0b2be6354e281e",
+    signature:
+      "0x67eaefab6ec390d1256087b97bf74d592847c3df24b63afa85eb2f83468e5981c1aa3e3cdfc6556c425e789947fe00f99a97c1319033b4448d1d9d922de6a68900",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 1 with recovery id 1, v = 28",
+    hash: "0xb2cdae65549363ef1d64371c400225cdb1b7ee18582c83021caaa2db9e9788bb",
+    signature:
+      "0x3da3c06a4360493ffdf6a8ddd56833ed976a738df453fcf90c4e3a7456edfdc35c6c747bfa06c43c5abc78d20eefac5633e60eecfa6f32185af2376fd0b246b81c",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 1 with recovery id 1, v = 1",
+    hash: "0xb2cdae65549363ef1d64371c400225cdb1b7ee18582c83021caaa2db9e9788bb",
+    signature:
+      "0x3da3c06a4360493ffdf6a8ddd56833ed976a738df453fcf90c4e3a7456edfdc35c6c747bfa06c43c5abc78d20eefac5633e60eecfa6f32185af2376fd0b246b801",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    failure: null,
+  },
+  {
+    name: "key 1, message 1 with recovery id 1, high s, v = 27",
+    hash: "0xb2cdae65549363ef1d64371c400225cdb1b7ee18582c83021caaa2db9e9788bb",
+    signature:
+      "0x3da3c06a4360493ffdf6a8ddd56833ed976a738df453fcf90c4e3a7456edfdc3a3938b8405f93bc3a543872df11053a886c8cdf9b4d96e2364e0271cff83fa891b",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 1, message 1 with recovery id 1, high s, v = 0",
+    hash: "0xb2cdae65549363ef1d64371c400225cdb1b7ee18582c83021caaa2db9e9788bb",
+    signature:
+      "0x3da3c06a4360493ffdf6a8ddd56833ed976a738df453fcf90c4e3a7456edfdc3a3938b8405f93bc3a543872df11053a886c8cdf9b4d96e2364e0271cff83fa8900",
+    legacy: "0x6bd05bb061e1c0debbea3757e35dd57b0930c1ed",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 0 with recovery id 0, v = 27",
+    hash: "0xd8832c2c6dc883c1c93d6ec6de753174661e1d9f853ce91bfadf12df15c5f1a1",
+    signature:
+      "0x6608db66ee42411159d1652ec0cc1f73e1ce1344bd41844d6cf881bd1f1910b15021502a7471590d698eb4f9e14638a05b52000fc44855b47275d763364031f91b",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 0 with recovery id 0, v = 0",
+    hash: "0xd8832c2c6dc883c1c93d6ec6de753174661e1d9f853ce91bfadf12df15c5f1a1",
+    signature:
+      "0x6608db66ee42411159d1652ec0cc1f73e1ce1344bd41844d6cf881bd1f1910b15021502a7471590d698eb4f9e14638a05b52000fc44855b47275d763364031f900",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 0 with recovery id 0, high s, v = 28",
+    hash: "0xd8832c2c6dc883c1c93d6ec6de753174661e1d9f853ce91bfadf12df15c5f1a1",
+    signature:
+      "0x6608db66ee42411159d1652ec0cc1f73e1ce1344bd41844d6cf881bd1f1910b1afdeafd58b8ea6f296714b061eb9c75e5f5cdcd6eb004a874d5c872999f60f481c",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 0 with recovery id 0, high s, v = 1",
+    hash: "0xd8832c2c6dc883c1c93d6ec6de753174661e1d9f853ce91bfadf12df15c5f1a1",
+    signature:
+      "0x6608db66ee42411159d1652ec0cc1f73e1ce1344bd41844d6cf881bd1f1910b1afdeafd58b8ea6f296714b061eb9c75e5f5cdcd6eb004a874d5c872999f60f4801",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 1 with recovery id 0, v = 27",
+    hash: "0xc19748c5b6c588bca3344668dea82fa09e9bc695c9d6ffb32927f7fa0e0d8b03",
+    signature:
+      "0xea9618c493dbde2dfe68f2ffaad4b1661fb191a46c846aa61e2dd379a29b570525f7e66e53c0c6c4993d90f7b8173551150d7922c1a25aa418a45e4aca95650b1b",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 1 with recovery id 0, v = 0",
+    hash: "0xc19748c5b6c588bca3344668dea82fa09e9bc695c9d6ffb32927f7fa0e0d8b03",
+    signature:
+      "0xea9618c493dbde2dfe68f2ffaad4b1661fb191a46c846aa61e2dd379a29b570525f7e66e53c0c6c4993d90f7b8173551150d7922c1a25aa418a45e4aca95650b00",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 1 with recovery id 0, high s, v = 28",
+    hash: "0xc19748c5b6c588bca3344668dea82fa09e9bc695c9d6ffb32927f7fa0e0d8b03",
+    signature:
+      "0xea9618c493dbde2dfe68f2ffaad4b1661fb191a46c846aa61e2dd379a29b5705da081991ac3f393b66c26f0847e8caada5a163c3eda64597a72e004205a0dc361c",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 1 with recovery id 0, high s, v = 1",
+    hash: "0xc19748c5b6c588bca3344668dea82fa09e9bc695c9d6ffb32927f7fa0e0d8b03",
+    signature:
+      "0xea9618c493dbde2dfe68f2ffaad4b1661fb191a46c846aa61e2dd379a29b5705da081991ac3f393b66c26f0847e8caada5a163c3eda64597a72e004205a0dc3601",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 0 with recovery id 1, v = 28",
+    hash: "0xee45266a9bec61f5f5cf88c95472169ac964564d77699f22b9c372d64d90a874",
+    signature:
+      "0xf12a547bb9cc9f195ee35971b96f08be074caeecdb7abba687c67b003b1fbd805099684d702dd554f841a6a216a85ae56187bc63d1cc8942db19bb4da59fab021c",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 0 with recovery id 1, v = 1",
+    hash: "0xee45266a9bec61f5f5cf88c95472169ac964564d77699f22b9c372d64d90a874",
+    signature:
+      "0xf12a547bb9cc9f195ee35971b96f08be074caeecdb7abba687c67b003b1fbd805099684d702dd554f841a6a216a85ae56187bc63d1cc8942db19bb4da59fab0201",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 0 with recovery id 1, high s, v = 27",
+    hash: "0xee45266a9bec61f5f5cf88c95472169ac964564d77699f22b9c372d64d90a874",
+    signature:
+      "0xf12a547bb9cc9f195ee35971b96f08be074caeecdb7abba687c67b003b1fbd80af6697b28fd22aab07be595de957a51959272082dd7c16f8e4b8a33f2a96963f1b",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 0 with recovery id 1, high s, v = 0",
+    hash: "0xee45266a9bec61f5f5cf88c95472169ac964564d77699f22b9c372d64d90a874",
+    signature:
+      "0xf12a547bb9cc9f195ee35971b96f08be074caeecdb7abba687c67b003b1fbd80af6697b28fd22aab07be595de957a51959272082dd7c16f8e4b8a33f2a96963f00",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 1 with recovery id 1, v = 28",
+    hash: "0xe46a6f523850613711c1c864e5dde1250a712f8427011a9140b4774d080074fa",
+    signature:
+      "0x1806e57cd707c091e92117970b9a601949ce826eef56c82e795b715200d70e4042052757b4e9aa9ca14168bb24c144437b24c353a6288b9b381f5b8a87ffef4e1c",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 1 with recovery id 1, v = 1",
+    hash: "0xe46a6f523850613711c1c864e5dde1250a712f8427011a9140b4774d080074fa",
+    signature:
+      "0x1806e57cd707c091e92117970b9a601949ce826eef56c82e795b715200d70e4042052757b4e9aa9ca14168bb24c144437b24c353a6288b9b381f5b8a87ffef4e01",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    failure: null,
+  },
+  {
+    name: "key 2, message 1 with recovery id 1, high s, v = 27",
+    hash: "0xe46a6f523850613711c1c864e5dde1250a712f8427011a9140b4774d080074fa",
+    signature:
+      "0x1806e57cd707c091e92117970b9a601949ce826eef56c82e795b715200d70e40bdfad8a84b1655635ebe9744db3ebbbb3f8a1993092014a087b30302483651f31b",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 2, message 1 with recovery id 1, high s, v = 0",
+    hash: "0xe46a6f523850613711c1c864e5dde1250a712f8427011a9140b4774d080074fa",
+    signature:
+      "0x1806e57cd707c091e92117970b9a601949ce826eef56c82e795b715200d70e40bdfad8a84b1655635ebe9744db3ebbbb3f8a1993092014a087b30302483651f300",
+    legacy: "0x6052090784726f2d234f1dcdac7b356c4d79f48f",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, r = 0",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0x00000000000000000000000000000000000000000000000000000000000000004d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16000",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature",
+  },
+  {
+    name: "key 0, r = n",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641414d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16000",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature",
+  },
+  {
+    name: "key 0, r = n + 1",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641424d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16000",
+    legacy: "0x4d16831950866862affe88e2641e467e1fef2d7f",
+    signer: null,
+    failure: "Invalid signature",
+  },
+  {
+    name: "key 0, r = 2^256 - 1",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff4d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16000",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature",
+  },
+  {
+    name: "key 0, s = 0",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a35000000000000000000000000000000000000000000000000000000000000000000",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature",
+  },
+  {
+    name: "key 0, s = n",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a35fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036414100",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, s = n + 1",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a35fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036414200",
+    legacy: "0x0ac1faac6b4fd39511162e5ef9101a48c87f11dc",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, s = 2^256 - 1",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a35ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00",
+    legacy: "0x220ccd44fb29a2f1a69b8480f5a48059aeb6c9e1",
+    signer: null,
+    failure: "Invalid signature s value",
+  },
+  {
+    name: "key 0, v = 2",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16002",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature recovery id",
+  },
+  {
+    name: "key 0, v = 3",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba16003",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature recovery id",
+  },
+  {
+    name: "key 0, v = 26",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba1601a",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature recovery id",
+  },
+  {
+    name: "key 0, v = 29",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba1601d",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature recovery id",
+  },
+  {
+    name: "key 0, v = 30",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba1601e",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature recovery id",
+  },
+  {
+    name: "key 0, v = 255",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba160ff",
+    legacy: "rejected",
+    signer: null,
+    failure: "Invalid signature recovery id",
+  },
+  {
+    name: "key 0, 64 bytes",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba160",
+    legacy: "panicked",
+    signer: null,
+    failure: "Invalid signature length",
+  },
+  {
+    name: "key 0, 66 bytes",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature:
+      "0xe07a6abb60f5b8935b8614dcaba9224d89f15fe61c30274b8a277c2770d03a354d3beded665f901dcfa2c4311952572755d3fe1944110e91877a7671376ba1600000",
+    legacy: "panicked",
+    signer: null,
+    failure: "Invalid signature length",
+  },
+  {
+    name: "key 0, empty",
+    hash: "0xc5e7c0c7f2d5760e74643ec2e492840b021e2d86c319deadc943804043cc1a4b",
+    signature: "0x",
+    legacy: "panicked",
+    signer: null,
+    failure: "Invalid signature length",
+  },
+];
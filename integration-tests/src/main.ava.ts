@@ -1,8 +1,10 @@
 import { SignerWithAddress } from "@nomiclabs/hardhat-ethers/signers";
 import anyTest, { TestFn } from "ava";
 import { sortBy } from "lodash";
-import { NEAR, NearAccount, Worker } from "near-workspaces";
+import { KeyPair, NEAR, NearAccount, Worker } from "near-workspaces";
+import fs from "fs";
 import path from "path";
+import { ECRECOVER_SIGNERS, ECRECOVER_VECTORS } from "./ecrecover-vectors";
 import Utils from "./utils";
 const { ethers } = require("hardhat");
 
@@ -50,6 +52,53 @@ const initContract = async (root: NearAccount, contract: NearAccount) => {
   });
 };
 
+// Deploys another gateway as the auth module of `contract`, with the current operators
+const deployAuthModule = async (root: NearAccount, contract: NearAccount) => {
+  const authModule = await root.createSubAccount("auth_module");
+
+  await authModule.deploy(
+    path.join(__dirname, "../../dist/axelar_cgp_near.wasm")
+  );
+
+  // The gateway owns its auth module, so it can transfer operatorship on it
+  await contract.call(authModule, "new", {
+    recent_operators: [
+      ethers.utils.defaultAbiCoder.encode(
+        ["address[]", "uint256[]", "uint256"],
+        [Utils.getAddresses(operators), operators.map(() => 1), threshold]
+      ),
+    ],
+  });
+
+  return authModule;
+};
+
+// Deploys a timelock multisig of the given signers
+const deployMultisig = async (
+  root: NearAccount,
+  signers: NearAccount[],
+  threshold: number,
+  emergencyThreshold: number,
+  delay: number
+) => {
+  const multisig = await root.createSubAccount("timelock_multisig", {
+    initialBalance: NEAR.parse("3 N").toJSON(),
+  });
+
+  await multisig.deploy(
+    path.join(__dirname, "../../timelock-multisig/target/wasm32-unknown-unknown/release/timelock_multisig.wasm")
+  );
+
+  await root.call(multisig, "new", {
+    signers: signers.map((signer) => signer.accountId),
+    threshold,
+    emergency_threshold: emergencyThreshold,
+    delay,
+  });
+
+  return multisig;
+};
+
 test.before(async (t) => {
   wallets = await ethers.getSigners();
   wallets = wallets;
@@ -262,6 +311,142 @@ test("Auth - reject the proof from the operators older than key retention", asyn
   );
 });
 
+test("Auth - should allow owner to shorten the key retention", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  t.is(await contract.view("old_key_retention", {}), OLD_KEY_RETENTION);
+
+  await root.call(
+    contract,
+    "set_old_key_retention",
+    { old_key_retention: 2 },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(await contract.view("old_key_retention", {}), 2);
+
+  const data = "0x123abc123abc";
+
+  const message = ethers.utils.hashMessage(
+    ethers.utils.arrayify(ethers.utils.keccak256(data))
+  );
+
+  const getProof = (operators: SignerWithAddress[]) =>
+    Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      operators.map(() => 1),
+      threshold,
+      operators.slice(0, threshold)
+    );
+
+  // The operators of the previous epoch are still within the window
+  const isCurrentOperators = await contract.view("validate_proof", {
+    message_hash: message,
+    proof: await getProof(previousOperators[previousOperators.length - 1]),
+  });
+
+  t.is(isCurrentOperators, false);
+
+  const error = await t.throwsAsync(
+    contract.view("validate_proof", {
+      message_hash: message,
+      proof: await getProof(previousOperators[previousOperators.length - 2]),
+    })
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Invalid epoch
+});
+
+test("Auth - should not allow non-owner or zero key retention updates", async (t) => {
+  const { contract, root, john } = t.context.accounts;
+
+  const notOwnerError = await t.throwsAsync(
+    john.call(
+      contract,
+      "set_old_key_retention",
+      { old_key_retention: 2 },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(notOwnerError?.message); // uncomment to see the error message
+
+  t.not(notOwnerError, undefined); // Owner only
+
+  const zeroError = await t.throwsAsync(
+    root.call(
+      contract,
+      "set_old_key_retention",
+      { old_key_retention: 0 },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(zeroError?.message); // uncomment to see the error message
+
+  t.not(zeroError, undefined); // Invalid key retention
+});
+
+test("Auth - should only grow the key retention once expired epochs are pruned", async (t) => {
+  const { contract, root, john } = t.context.accounts;
+
+  const data = "0x123abc123abc";
+
+  const message = ethers.utils.hashMessage(
+    ethers.utils.arrayify(ethers.utils.keccak256(data))
+  );
+
+  const expiredOperators = previousOperators[0];
+
+  const getExpiredProof = () =>
+    Utils.getWeightedSignaturesProof(
+      data,
+      expiredOperators,
+      expiredOperators.map(() => 1),
+      threshold,
+      expiredOperators.slice(0, threshold)
+    );
+
+  const growError = await t.throwsAsync(
+    root.call(
+      contract,
+      "set_old_key_retention",
+      { old_key_retention: OLD_KEY_RETENTION + 8 },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(growError?.message); // uncomment to see the error message
+
+  t.not(growError, undefined); // Expired epochs not pruned
+
+  await john.call(contract, "prune_epochs", { limit: 100 });
+
+  await root.call(
+    contract,
+    "set_old_key_retention",
+    { old_key_retention: OLD_KEY_RETENTION + 8 },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(await contract.view("old_key_retention", {}), OLD_KEY_RETENTION + 8);
+
+  // The expired operator set is back in the window, but stays rejected
+  const error = await t.throwsAsync(
+    contract.view("validate_proof", {
+      message_hash: message,
+      proof: await getExpiredProof(),
+    })
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Invalid epoch
+});
+
 test("Auth - validate the proof for a single operator", async (t) => {
   const { contract, root } = t.context.accounts;
 
@@ -340,133 +525,565 @@ test("Auth - validate the proof for a single signer", async (t) => {
   t.is(isCurrentOperators, true);
 });
 
-test("Auth - should allow owner to transfer operatorship", async (t) => {
-  const { contract, root } = t.context.accounts;
+test("Auth - inspect a valid proof from the current operators", async (t) => {
+  const { contract } = t.context.accounts;
 
-  const newOperators = [
-    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
-    "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88",
-  ];
+  const data = "0x123abc123abc";
 
-  const didTransferOperatorship = await root.call(
-    contract,
-    "transfer_operatorship",
-    {
-      params: await Utils.getTransferWeightedOperatorshipCommand(
-        newOperators,
-        [1, 1],
-        2
-      ),
-    },
-    { attachedDeposit: "0" }
+  const message = ethers.utils.hashMessage(
+    ethers.utils.arrayify(ethers.utils.keccak256(data))
   );
 
-  t.is(didTransferOperatorship, true);
+  const report: any = await contract.view("inspect_proof", {
+    message_hash: message,
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      operators.map(() => 1),
+      threshold,
+      operators.slice(0, threshold)
+    ),
+  });
+
+  t.is(report.failure, null);
+  t.is(report.epoch, previousOperators.length + 1);
+  t.is(report.is_current, true);
+  t.is(report.accumulated_weight, threshold.toString());
+  t.is(report.threshold, threshold.toString());
+  t.deepEqual(
+    report.signatures,
+    operators.slice(0, threshold).map(({ address }, i) => ({
+      signer: address.toLowerCase(),
+      operator_index: i,
+      weight: "1",
+    }))
+  );
 });
 
-test("Auth - should not allow transferring operatorship to address zero", async (t) => {
-  const { contract, root } = t.context.accounts;
+test("Auth - inspect a proof without panicking on failure", async (t) => {
+  const { contract } = t.context.accounts;
 
-  const newOperators = [
-    ADDRESS_ZERO,
-    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
-  ];
+  const data = "0x123abc123abc";
 
-  const error = await t.throwsAsync(
-    root.call(
-      contract,
-      "transfer_operatorship",
-      {
-        params: await Utils.getTransferWeightedOperatorshipCommand(
-          newOperators,
-          [1, 1],
-          2
-        ),
-      },
-      { attachedDeposit: "0" }
-    )
+  const message = ethers.utils.hashMessage(
+    ethers.utils.arrayify(ethers.utils.keccak256(data))
   );
 
-  // t.log(error?.message); // uncomment to see the error message
-
-  t.not(error, undefined); // Invalid operators
-});
+  const lowWeight: any = await contract.view("inspect_proof", {
+    message_hash: message,
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      operators.map(() => 1),
+      threshold,
+      operators.slice(0, threshold - 1)
+    ),
+  });
 
-test("Auth - should not allow transferring operatorship to duplicated operators", async (t) => {
-  const { contract, root } = t.context.accounts;
+  t.is(lowWeight.failure, "Low signature weight");
+  t.is(lowWeight.accumulated_weight, (threshold - 1).toString());
 
-  const newOperators = [
-    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
-    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
-  ];
+  const wrongSigners: any = await contract.view("inspect_proof", {
+    message_hash: message,
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      operators.map(() => 1),
+      threshold,
+      wallets.slice(0, threshold)
+    ),
+  });
 
-  const error = await t.throwsAsync(
-    root.call(
-      contract,
-      "transfer_operatorship",
-      {
-        params: await Utils.getTransferWeightedOperatorshipCommand(
-          newOperators,
-          [1, 1],
-          2
-        ),
-      },
-      { attachedDeposit: "0" }
-    )
+  t.regex(wrongSigners.failure, /^Malformed signers/);
+  t.is(
+    wrongSigners.signatures[0].signer,
+    sortBy(Utils.getAddresses(wallets.slice(0, threshold)), (address) =>
+      address.toLowerCase()
+    )[0].toLowerCase()
   );
+  t.is(wrongSigners.signatures[0].operator_index, null);
 
-  // t.log(error?.message); // uncomment to see the error message
+  const unknownSigners: any = await contract.view("inspect_proof", {
+    message_hash: message,
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      operators.map(() => 2),
+      threshold,
+      operators.slice(0, threshold)
+    ),
+  });
 
-  t.not(error, undefined); // Invalid operators
+  t.is(unknownSigners.failure, "Invalid epoch");
+  t.is(unknownSigners.epoch, 0);
+  t.is(unknownSigners.is_current, false);
+
+  const malformed: any = await contract.view("inspect_proof", {
+    message_hash: message,
+    proof: "0x1234",
+  });
+
+  t.not(malformed.failure, null);
 });
 
-test("Auth - should not allow transferring operatorship to unsorted operators", async (t) => {
-  const { contract, root } = t.context.accounts;
+test("Auth - recover the same signers as ethers for both recovery byte forms", async (t) => {
+  const { contract } = t.context.accounts;
 
-  const newOperators = [
-    "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88",
-    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
-  ];
+  const hash = ethers.utils.arrayify(ethers.utils.keccak256("0x123abc123abc"));
+  const message = ethers.utils.hashMessage(hash);
 
-  const error = await t.throwsAsync(
-    root.call(
-      contract,
-      "transfer_operatorship",
-      {
-        params: await ethers.utils.defaultAbiCoder.encode(
-          ["address[]", "uint256[]", "uint256"],
-          [newOperators, [1, 1], 2]
-        ),
-      },
-      { attachedDeposit: "0" }
-    )
+  const signatures = await Promise.all(
+    operators.map((wallet) => wallet.signMessage(hash))
   );
 
-  // t.log(error?.message); // uncomment to see the error message
+  // Operators sign with v = 27/28, the same signatures with v = 0/1 must recover identically
+  const normalized = signatures.map((signature) => {
+    const bytes = ethers.utils.arrayify(signature);
+    bytes[64] -= 27;
+    return ethers.utils.hexlify(bytes);
+  });
 
-  t.not(error, undefined); // Invalid operators
+  for (const sigs of [signatures, normalized]) {
+    const report: any = await contract.view("inspect_proof", {
+      message_hash: message,
+      proof: ethers.utils.defaultAbiCoder.encode(
+        ["address[]", "uint256[]", "uint256", "bytes[]"],
+        [
+          Utils.getAddresses(operators),
+          operators.map(() => 1),
+          threshold,
+          sigs,
+        ]
+      ),
+    });
+
+    t.is(report.failure, null);
+    t.deepEqual(
+      report.signatures.map(({ signer }: any) => signer),
+      signatures.map((signature) =>
+        ethers.utils.recoverAddress(message, signature).toLowerCase()
+      )
+    );
+  }
 });
 
-test("Auth - should not allow operatorship transfer to the previous operators", async (t) => {
-  const { contract, root } = t.context.accounts;
+test("Auth - reject malformed and malleable signatures", async (t) => {
+  const { contract } = t.context.accounts;
 
-  const updatedOperators = Utils.getAddresses(operators.slice(0, threshold));
+  const hash = ethers.utils.arrayify(ethers.utils.keccak256("0x123abc123abc"));
+  const message = ethers.utils.hashMessage(hash);
 
-  const didTransferOperatorship = await root.call(
-    contract,
-    "transfer_operatorship",
-    {
-      params: await Utils.getTransferWeightedOperatorshipCommand(
-        updatedOperators,
-        updatedOperators.map(() => 1),
-        threshold
-      ),
-    },
-    { attachedDeposit: "0" }
+  const signers = operators.slice(0, threshold);
+  const signatures = await Promise.all(
+    signers.map((wallet) => wallet.signMessage(hash))
   );
 
-  t.is(didTransferOperatorship, true);
-
+  const getProof = (firstSignature: string) =>
+    ethers.utils.defaultAbiCoder.encode(
+      ["address[]", "uint256[]", "uint256", "bytes[]"],
+      [
+        Utils.getAddresses(operators),
+        operators.map(() => 1),
+        threshold,
+        [firstSignature, ...signatures.slice(1)],
+      ]
+    );
+
+  const withV = (signature: string, v: number) =>
+    ethers.utils.hexConcat([ethers.utils.hexDataSlice(signature, 0, 64), [v]]);
+
+  // The same signature with s replaced by n - s and the recovery byte flipped still recovers
+  // the signer, so it has to be rejected to keep signatures non-malleable
+  const { r, s, v } = ethers.utils.splitSignature(signatures[0]);
+  const curveOrder = ethers.BigNumber.from(
+    "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+  );
+  const highS = ethers.utils.hexConcat([
+    r,
+    ethers.utils.hexZeroPad(curveOrder.sub(s).toHexString(), 32),
+    [v === 27 ? 28 : 27],
+  ]);
+
+  t.is(
+    ethers.utils.recoverAddress(message, highS),
+    signers[0].address
+  );
+
+  const vectors: [string, string][] = [
+    [ethers.utils.hexDataSlice(signatures[0], 0, 64), "Invalid signature length"],
+    [ethers.utils.hexConcat([signatures[0], "0x00"]), "Invalid signature length"],
+    [withV(signatures[0], 2), "Invalid signature recovery id"],
+    [withV(signatures[0], 29), "Invalid signature recovery id"],
+    [highS, "Invalid signature s value"],
+  ];
+
+  for (const [signature, reason] of vectors) {
+    const report: any = await contract.view("inspect_proof", {
+      message_hash: message,
+      proof: getProof(signature),
+    });
+
+    t.is(report.failure, reason);
+    t.is(report.signatures[0].signer, null);
+
+    const error = await t.throwsAsync(
+      contract.view("validate_proof", {
+        message_hash: message,
+        proof: getProof(signature),
+      })
+    );
+
+    // t.log(error?.message); // uncomment to see the error message
+
+    t.not(error, undefined); // reason
+  }
+
+  t.is(
+    await contract.view("validate_proof", {
+      message_hash: message,
+      proof: getProof(signatures[0]),
+    }),
+    true
+  );
+});
+
+test("Auth - recover signers like the libsecp256k1 ecrecover did", async (t) => {
+  const { contract } = t.context.accounts;
+
+  for (const { seed, address } of ECRECOVER_SIGNERS) {
+    t.is(
+      ethers.utils.computeAddress(
+        ethers.utils.keccak256(ethers.utils.toUtf8Bytes(seed))
+      ),
+      ethers.utils.getAddress(address)
+    );
+  }
+
+  for (const { name, hash, signature, legacy, signer, failure } of ECRECOVER_VECTORS) {
+    const report: any = await contract.view("inspect_proof", {
+      message_hash: hash,
+      proof: ethers.utils.defaultAbiCoder.encode(
+        ["address[]", "uint256[]", "uint256", "bytes[]"],
+        [
+          Utils.getAddresses(operators),
+          operators.map(() => 1),
+          threshold,
+          [signature],
+        ]
+      ),
+    });
+
+    t.is(report.signatures[0].signer, signer, name);
+
+    if (signer !== null) {
+      // Recovered signers match the libsecp256k1 ecrecover and ethers byte for byte
+      t.is(signer, legacy, name);
+      t.is(
+        ethers.utils.recoverAddress(hash, signature),
+        ethers.utils.getAddress(signer),
+        name
+      );
+
+      // The vector signers are not operators, so the proof fails after recovering them
+      t.regex(report.failure, /^Malformed signers/, name);
+    } else {
+      t.is(report.failure, failure, name);
+    }
+  }
+});
+
+test("Auth - validate the proof from operators mixing secp256k1 and ed25519 keys", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const mixedOperators = Utils.sortOperators([
+    ...operators.slice(0, 3),
+    KeyPair.fromRandom("ed25519"),
+    KeyPair.fromRandom("ed25519"),
+  ]);
+  const weights = [1, 1, 1, 3, 3];
+  const mixedThreshold = 4;
+
+  await root.call(
+    contract,
+    "transfer_operatorship",
+    {
+      params: ethers.utils.hexlify(
+        Utils.getBorshWeightedSigners(mixedOperators, weights, mixedThreshold)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  const currentOperators: any = await contract.view("current_operators", {});
+
+  t.deepEqual(
+    currentOperators.operators,
+    mixedOperators.map(Utils.getOperatorKeyString)
+  );
+
+  const hash = ethers.utils.keccak256("0x123abc123abc");
+  const message = ethers.utils.hashMessage(ethers.utils.arrayify(hash));
+
+  t.is(
+    await contract.view("epoch_for_hash", {
+      hash: Utils.getMixedWeightedSignersHash(
+        mixedOperators,
+        weights,
+        mixedThreshold
+      ),
+    }),
+    previousOperators.length + 2
+  );
+
+  // One secp256k1 and one ed25519 operator meet the threshold together
+  const signers = [mixedOperators[0], mixedOperators[4]];
+
+  const report: any = await contract.view("inspect_proof", {
+    message_hash: message,
+    proof: await Utils.getMixedBorshSignaturesProof(
+      hash,
+      mixedOperators,
+      weights,
+      mixedThreshold,
+      [mixedOperators[1], mixedOperators[3]]
+    ),
+  });
+
+  t.is(report.failure, null);
+  t.deepEqual(
+    report.signatures.map(({ signer }: any) => signer),
+    [mixedOperators[1], mixedOperators[3]].map(Utils.getOperatorKeyString)
+  );
+
+  t.is(
+    await contract.view("validate_proof", {
+      message_hash: message,
+      proof: await Utils.getMixedBorshSignaturesProof(
+        hash,
+        mixedOperators,
+        weights,
+        mixedThreshold,
+        signers
+      ),
+    }),
+    true
+  );
+
+  // A single ed25519 operator does not meet the threshold on its own
+  const error = await t.throwsAsync(
+    contract.view("validate_proof", {
+      message_hash: message,
+      proof: await Utils.getMixedBorshSignaturesProof(
+        hash,
+        mixedOperators,
+        weights,
+        mixedThreshold,
+        [mixedOperators[3]]
+      ),
+    })
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Low signature weight
+});
+
+test("Auth - should allow owner to transfer operatorship", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const newOperators = [
+    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
+    "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88",
+  ];
+
+  const didTransferOperatorship = await root.call(
+    contract,
+    "transfer_operatorship",
+    {
+      params: await Utils.getTransferWeightedOperatorshipCommand(
+        newOperators,
+        [1, 1],
+        2
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(didTransferOperatorship, true);
+});
+
+test("Auth - should initialize and transfer operatorship from JSON operator sets", async (t) => {
+  const { root, john } = t.context.accounts;
+
+  const contract = await root.createSubAccount("json_gateway");
+
+  await contract.deploy(
+    path.join(__dirname, "../../dist/axelar_cgp_near.wasm")
+  );
+
+  // Operators in any order, each weight stays with its operator
+  const unsorted = [...operators].reverse();
+
+  await root.call(contract, "new_with_signers", {
+    recent_signers: [
+      {
+        operators: Utils.getAddresses(unsorted),
+        weights: unsorted.map((_, i) => i + 1),
+        threshold,
+      },
+    ],
+  });
+
+  const expectedHash = ethers.utils.keccak256(
+    ethers.utils.defaultAbiCoder.encode(
+      ["address[]", "uint256[]", "uint256"],
+      [
+        Utils.getAddresses(operators),
+        operators.map((_, i) => operators.length - i),
+        threshold,
+      ]
+    )
+  );
+
+  t.is(await contract.view("hash_for_epoch", { epoch: 1 }), expectedHash);
+
+  const newOperators = sortBy(wallets.slice(0, 3), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+  const newSigners = {
+    operators: Utils.getAddresses(newOperators).reverse(),
+    weights: newOperators.map(() => "1"),
+    threshold: "2",
+  };
+
+  const error = await t.throwsAsync(
+    john.call(contract, "transfer_operatorship_with_signers", {
+      new_signers: newSigners,
+    })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // only the owner can transfer operatorship
+
+  await root.call(contract, "transfer_operatorship_with_signers", {
+    new_signers: newSigners,
+  });
+
+  const payload = await Utils.getTransferWeightedOperatorshipCommand(
+    Utils.getAddresses(newOperators),
+    newOperators.map(() => 1),
+    2
+  );
+
+  t.is(await contract.view("current_epoch"), 2);
+  t.is(
+    await contract.view("hash_for_epoch", { epoch: 2 }),
+    ethers.utils.keccak256(payload)
+  );
+});
+
+test("Auth - should not allow transferring operatorship to address zero", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const newOperators = [
+    ADDRESS_ZERO,
+    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
+  ];
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "transfer_operatorship",
+      {
+        params: await Utils.getTransferWeightedOperatorshipCommand(
+          newOperators,
+          [1, 1],
+          2
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Invalid operators
+});
+
+test("Auth - should not allow transferring operatorship to duplicated operators", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const newOperators = [
+    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
+    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
+  ];
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "transfer_operatorship",
+      {
+        params: await Utils.getTransferWeightedOperatorshipCommand(
+          newOperators,
+          [1, 1],
+          2
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Invalid operators
+});
+
+test("Auth - should not allow transferring operatorship to unsorted operators", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const newOperators = [
+    "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88",
+    "0x6D4017D4b1DCd36e6EA88b7900e8eC64A1D1315b",
+  ];
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "transfer_operatorship",
+      {
+        params: await ethers.utils.defaultAbiCoder.encode(
+          ["address[]", "uint256[]", "uint256"],
+          [newOperators, [1, 1], 2]
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Invalid operators
+});
+
+test("Auth - should not allow operatorship transfer to the previous operators", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const updatedOperators = Utils.getAddresses(operators.slice(0, threshold));
+
+  const didTransferOperatorship = await root.call(
+    contract,
+    "transfer_operatorship",
+    {
+      params: await Utils.getTransferWeightedOperatorshipCommand(
+        updatedOperators,
+        updatedOperators.map(() => 1),
+        threshold
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(didTransferOperatorship, true);
+
   const oldOperators = Utils.getAddresses(operators);
 
   const error = await t.throwsAsync(
@@ -583,23 +1200,119 @@ test("Auth - should not allow transferring operatorship with invalid number of w
   t.not(error, undefined); // Invalid weights
 });
 
-test("Auth - should expose correct hashes and epoch", async (t) => {
-  const { contract } = t.context.accounts;
+test("Auth - validate the proof from operators with stake-sized weights", async (t) => {
+  const { contract, root } = t.context.accounts;
 
-  const operatorsHistory = [...previousOperators, operators];
+  // Weights and threshold well above u64::MAX, as with token-denominated stake
+  const weights = operators.map((_, i) =>
+    ethers.utils.parseUnits(`${i + 1}000000`, 24).toString()
+  );
+  const stakeThreshold = ethers.utils.parseUnits("15000000", 24).toString();
 
-  await Promise.all(
-    operatorsHistory.map(async (operators, i) => {
-      const payload = await Utils.getTransferWeightedOperatorshipCommand(
+  const transferTx = await root.callRaw(
+    contract,
+    "transfer_operatorship",
+    {
+      params: await Utils.getTransferWeightedOperatorshipCommand(
         Utils.getAddresses(operators),
-        operators.map(() => 1),
-        threshold
-      );
+        weights,
+        stakeThreshold
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
 
-      const hash = ethers.utils.keccak256(payload);
+  const transferEvents = Utils.getAxelarEvents(transferTx).filter(
+    (event: any) => event.event === "operatorship_transferred_event"
+  );
 
-      const hashForEpoch = await contract.view("hash_for_epoch", {
-        epoch: i + 1,
+  // Version 1.0.0 cannot carry the weights without losing precision, only 2.0.0 is emitted
+  t.deepEqual(
+    transferEvents.map((event: any) => event.version),
+    ["2.0.0"]
+  );
+  t.deepEqual(transferEvents[0].data.new_weights, weights);
+  t.is(transferEvents[0].data.new_threshold, stakeThreshold);
+
+  const data = "0x123abc123abc";
+
+  const message = ethers.utils.hashMessage(
+    ethers.utils.arrayify(ethers.utils.keccak256(data))
+  );
+
+  // The three heaviest operators hold 15M of stake, meeting the threshold
+  const isCurrentOperators = await contract.view("validate_proof", {
+    message_hash: message,
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      weights,
+      stakeThreshold,
+      operators.slice(3)
+    ),
+  });
+
+  t.is(isCurrentOperators, true);
+
+  // The three lightest operators hold 6M of stake, below the threshold
+  const error = await t.throwsAsync(
+    contract.view("validate_proof", {
+      message_hash: message,
+      proof: await Utils.getWeightedSignaturesProof(
+        data,
+        operators,
+        weights,
+        stakeThreshold,
+        operators.slice(0, 3)
+      ),
+    })
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Low signature weight
+});
+
+test("Auth - should not allow transferring operatorship with overflowing weights", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "transfer_operatorship",
+      {
+        params: await Utils.getTransferWeightedOperatorshipCommand(
+          Utils.getAddresses(operators.slice(0, 2)),
+          [ethers.constants.MaxUint256.toString(), "1"],
+          "1"
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Invalid weights
+});
+
+test("Auth - should expose correct hashes and epoch", async (t) => {
+  const { contract } = t.context.accounts;
+
+  const operatorsHistory = [...previousOperators, operators];
+
+  await Promise.all(
+    operatorsHistory.map(async (operators, i) => {
+      const payload = await Utils.getTransferWeightedOperatorshipCommand(
+        Utils.getAddresses(operators),
+        operators.map(() => 1),
+        threshold
+      );
+
+      const hash = ethers.utils.keccak256(payload);
+
+      const hashForEpoch = await contract.view("hash_for_epoch", {
+        epoch: i + 1,
       });
 
       t.is(hashForEpoch, hash);
@@ -611,6 +1324,179 @@ test("Auth - should expose correct hashes and epoch", async (t) => {
   );
 });
 
+test("Auth - should expose operator sets per epoch", async (t) => {
+  const { contract } = t.context.accounts;
+
+  const operatorsHistory = [...previousOperators, operators];
+
+  t.is(await contract.view("current_epoch", {}), operatorsHistory.length);
+
+  const expected = (operators: SignerWithAddress[]) => ({
+    operators: Utils.getAddresses(operators).map((x) => x.toLowerCase()),
+    weights: operators.map(() => "1"),
+    threshold: threshold.toString(),
+    nonce: ethers.constants.HashZero,
+  });
+
+  t.deepEqual(
+    await contract.view("current_operators", {}),
+    expected(operators)
+  );
+
+  t.deepEqual(
+    await contract.view("operators_for_epoch", { epoch: 1 }),
+    expected(previousOperators[0])
+  );
+
+  const error = await t.throwsAsync(
+    contract.view("operators_for_epoch", {
+      epoch: operatorsHistory.length + 1,
+    })
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Unknown epoch
+});
+
+test("Auth - should initialize from the epochs of an existing deployment", async (t) => {
+  const { root } = t.context.accounts;
+
+  const contract = await root.createSubAccount("migrated_gateway");
+
+  await contract.deploy(
+    path.join(__dirname, "../../dist/axelar_cgp_near.wasm")
+  );
+
+  const operatorsHistory = [...previousOperators.slice(0, 3), operators];
+  const payloads = await Promise.all(
+    operatorsHistory.map((operators) =>
+      Utils.getTransferWeightedOperatorshipCommand(
+        Utils.getAddresses(operators),
+        operators.map(() => 1),
+        threshold
+      )
+    )
+  );
+
+  const error = await t.throwsAsync(
+    root.call(contract, "new_from_epochs", {
+      operator_sets: payloads.slice(1).map((payload, i) => [38 + i, payload]),
+      current_epoch: 40,
+      old_key_retention: 4,
+    })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Key retention window not covered
+
+  await root.call(contract, "new_from_epochs", {
+    operator_sets: payloads.map((payload, i) => [37 + i, payload]),
+    current_epoch: 40,
+    old_key_retention: 4,
+  });
+
+  t.is(await contract.view("current_epoch"), 40);
+  t.is(await contract.view("pruned_epoch"), 36);
+
+  for (const [i, payload] of payloads.entries()) {
+    const hash = ethers.utils.keccak256(payload);
+
+    t.is(await contract.view("hash_for_epoch", { epoch: 37 + i }), hash);
+    t.is(await contract.view("epoch_for_hash", { hash }), 37 + i);
+  }
+
+  const prunedError = await t.throwsAsync(
+    contract.view("hash_for_epoch", { epoch: 36 })
+  );
+  // t.log(prunedError?.message);
+  t.not(prunedError, undefined); // Epoch 36 has been pruned
+
+  const data = "0x123abc123abc";
+
+  const isCurrentOperators = await contract.view("validate_proof", {
+    message_hash: ethers.utils.hashMessage(
+      ethers.utils.arrayify(ethers.utils.keccak256(data))
+    ),
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      operators,
+      operators.map(() => 1),
+      threshold,
+      operators.slice(0, threshold)
+    ),
+  });
+
+  t.is(isCurrentOperators, true);
+});
+
+test("Auth - should allow anyone to prune epochs older than key retention", async (t) => {
+  const { contract, root, john } = t.context.accounts;
+
+  const currentEpoch = previousOperators.length + 1;
+  const expiredEpochs = currentEpoch - OLD_KEY_RETENTION;
+
+  const oldestHash = ethers.utils.keccak256(
+    await Utils.getTransferWeightedOperatorshipCommand(
+      Utils.getAddresses(previousOperators[0]),
+      previousOperators[0].map(() => 1),
+      threshold
+    )
+  );
+
+  const pruned = await john.call(contract, "prune_epochs", { limit: 100 });
+
+  t.is(pruned, expiredEpochs);
+  t.is(await contract.view("pruned_epoch", {}), expiredEpochs);
+
+  const hashError = await t.throwsAsync(
+    contract.view("hash_for_epoch", { epoch: 1 })
+  );
+
+  // t.log(hashError?.message); // uncomment to see the error message
+
+  t.not(hashError, undefined); // Epoch 1 has been pruned
+
+  const epochError = await t.throwsAsync(
+    contract.view("epoch_for_hash", { hash: oldestHash })
+  );
+
+  // t.log(epochError?.message); // uncomment to see the error message
+
+  t.not(epochError, undefined); // Epoch 1 has been pruned
+
+  // A pruned operator set cannot be registered again
+  const duplicateError = await t.throwsAsync(
+    root.call(
+      contract,
+      "transfer_operatorship",
+      {
+        params: await Utils.getTransferWeightedOperatorshipCommand(
+          Utils.getAddresses(previousOperators[0]),
+          previousOperators[0].map(() => 1),
+          threshold
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(duplicateError?.message); // uncomment to see the error message
+
+  t.not(duplicateError, undefined); // Duplicate operators
+
+  // Epochs within the retention window are kept
+  const retainedHash = await contract.view("hash_for_epoch", {
+    epoch: expiredEpochs + 1,
+  });
+
+  t.is(
+    await contract.view("epoch_for_hash", { hash: retainedHash }),
+    expiredEpochs + 1
+  );
+
+  t.is(await john.call(contract, "prune_epochs", { limit: 100 }), 0);
+});
+
 // Gateway Tests
 
 test("Gateway - should fail if chain id mismatches", async (t) => {
@@ -783,41 +1669,2396 @@ test("Gateway - should approve and validate contract call", async (t) => {
   t.is(isApprovedAfter, false);
 });
 
-test("Gateway - call contract event will emit", async (t) => {
-  const { contract, root } = t.context.accounts;
+test("Gateway - should only let the owner execute approvals", async (t) => {
+  const { contract, root, john } = t.context.accounts;
 
-  const chain = "Polygon";
-  const destination = "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88";
-  const payload = ethers.utils.defaultAbiCoder.encode(
-    ["address", "address"],
-    [wallets[1].address, wallets[2].address]
+  const params = await Utils.getApproveContractCall(
+    "Polygon",
+    "address0x123",
+    contract.accountId,
+    ethers.utils.keccak256("0x1234"),
+    ethers.utils.keccak256("0x123abc123abc"),
+    17
   );
 
-  const event: {
-    address: string;
-    destination_chain: string;
-    destination_contract_address: string;
-    payload_hash: string;
-    payload: string;
-  } = await contract.call(
+  const commandId = Utils.getRandomID();
+
+  const input = await Utils.getSignedWeightedExecuteInput(
+    await Utils.buildCommandBatch(
+      CHAIN_ID,
+      [commandId],
+      ["approveContractCall"],
+      [params]
+    ),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const error = await t.throwsAsync(
+    john.call(contract, "execute", { input }, { attachedDeposit: "0" })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Owner only
+  t.is(await contract.view("is_command_executed", { command_id: commandId }), false);
+
+  const result = await root.call(
     contract,
-    "call_contract",
-    {
-      destination_chain: chain,
-      destination_contract_address: destination,
-      payload,
-    },
+    "execute",
+    { input },
     { attachedDeposit: "0" }
   );
 
-  t.is(event.address, contract.accountId);
-  t.is(event.destination_chain, chain);
-  t.is(event.destination_contract_address, destination);
-  t.is(event.payload_hash, ethers.utils.keccak256(payload));
-  t.is(event.payload, payload);
+  t.deepEqual(result, [true]);
+  t.is(await contract.view("is_command_executed", { command_id: commandId }), true);
+
+  // Executed approvals are skipped, whoever resubmits the batch
+  t.deepEqual(
+    await john.call(contract, "execute", { input }, { attachedDeposit: "0" }),
+    []
+  );
+});
+
+test("Gateway - should execute commands through a separate auth module", async (t) => {
+  const { contract, root, john } = t.context.accounts;
+
+  const authModule = await deployAuthModule(root, contract);
+
+  await root.call(contract, "set_auth_module", {
+    auth_module: authModule.accountId,
+  });
+
+  const newOperators = sortBy(wallets.slice(0, 3), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+
+  const ownerError = await t.throwsAsync(
+    root.call(contract, "transfer_operatorship", {
+      params: await Utils.getTransferWeightedOperatorshipCommand(
+        Utils.getAddresses(newOperators),
+        newOperators.map(() => 1),
+        2
+      ),
+    })
+  );
+  // t.log(ownerError?.message);
+  t.not(ownerError, undefined); // Operators are managed by the auth module
+
+  const approveId = Utils.getRandomID();
+  const transferId = Utils.getRandomID();
+
+  const data = await Utils.buildCommandBatch(
+    CHAIN_ID,
+    [approveId, transferId],
+    ["approveContractCall", "transferOperatorship"],
+    [
+      await Utils.getApproveContractCall(
+        "Polygon",
+        "address0x123",
+        contract.accountId,
+        ethers.utils.keccak256("0x1234"),
+        ethers.utils.keccak256("0x123abc123abc"),
+        17
+      ),
+      await Utils.getTransferWeightedOperatorshipCommand(
+        Utils.getAddresses(newOperators),
+        newOperators.map(() => 1),
+        2
+      ),
+    ]
+  );
+
+  const tx = await root.callRaw(
+    contract,
+    "execute",
+    {
+      input: await Utils.getSignedWeightedExecuteInput(
+        data,
+        operators,
+        operators.map(() => 1),
+        threshold,
+        operators.slice(0, threshold)
+      ),
+    },
+    { attachedDeposit: "0", gas: "300000000000000" }
+  );
+
+  t.deepEqual(tx.parseResult(), [true, true]);
+  t.is(await contract.view("is_command_executed", { command_id: transferId }), true);
+  t.is(await authModule.view("current_epoch"), 2);
+
+  // The operators signed the rotation, the auth module does not record it as an admin override
+  t.false(
+    Utils.getAxelarEvents(tx).some(
+      (event) =>
+        event.event === "admin_action_event" ||
+        event.event === "admin_override_event"
+    )
+  );
+  t.is(await authModule.view("admin_override_count"), 0);
+  t.true(
+    Utils.getAxelarEvents(tx).some(
+      (event) =>
+        event.event === "executed_event" &&
+        event.data.command_id === transferId
+    )
+  );
+
+  // Rotating back to a registered operator set fails in the auth module
+  const rejectedId = Utils.getRandomID();
+
+  const rejectedData = await Utils.buildCommandBatch(
+    CHAIN_ID,
+    [rejectedId],
+    ["transferOperatorship"],
+    [
+      await Utils.getTransferWeightedOperatorshipCommand(
+        Utils.getAddresses(operators),
+        operators.map(() => 1),
+        threshold
+      ),
+    ]
+  );
+
+  const rejectedTx = await john.callRaw(
+    contract,
+    "execute",
+    {
+      input: await Utils.getSignedWeightedExecuteInput(
+        rejectedData,
+        newOperators,
+        newOperators.map(() => 1),
+        2,
+        newOperators.slice(0, 2)
+      ),
+    },
+    { attachedDeposit: "0", gas: "300000000000000" }
+  );
+
+  t.deepEqual(rejectedTx.parseResult(), [false]);
+  t.is(await contract.view("is_command_executed", { command_id: rejectedId }), false);
+  t.false(
+    Utils.getAxelarEvents(rejectedTx).some(
+      (event) => event.event === "executed_event"
+    )
+  );
+  t.is(await authModule.view("current_epoch"), 2);
+
+  // The key retention is set on the auth module, the result is the one it gave
+  const setRetention = async (oldKeyRetention: number) => {
+    const commandId = Utils.getRandomID();
+
+    const retentionData = await Utils.buildCommandBatch(
+      CHAIN_ID,
+      [commandId],
+      ["setRetention"],
+      [ethers.utils.defaultAbiCoder.encode(["uint256"], [oldKeyRetention])]
+    );
+
+    const result = await john.call(
+      contract,
+      "execute",
+      {
+        input: await Utils.getSignedWeightedExecuteInput(
+          retentionData,
+          newOperators,
+          newOperators.map(() => 1),
+          2,
+          newOperators.slice(0, 2)
+        ),
+      },
+      { attachedDeposit: "0", gas: "300000000000000" }
+    );
+
+    return [result, await contract.view("is_command_executed", { command_id: commandId })];
+  };
+
+  t.deepEqual(await setRetention(1), [[true], true]);
+  t.is(await authModule.view("old_key_retention"), 1);
+
+  // Growing it back fails in the auth module, the first epoch expired but was not pruned
+  t.deepEqual(await setRetention(OLD_KEY_RETENTION), [[false], false]);
+  t.is(await authModule.view("old_key_retention"), 1);
+
+  // Commands checked against operators that rotated since are rejected, e.g. a second batch
+  // signed by the first operators that was in flight during the rotation
+  const staleRotationError = await t.throwsAsync(
+    contract.call(authModule, "transfer_operatorship_from_gateway", {
+      params: await Utils.getTransferWeightedOperatorshipCommand(
+        Utils.getAddresses(newOperators),
+        newOperators.map(() => 2),
+        2
+      ),
+      expected_epoch: 1,
+    })
+  );
+  t.regex(staleRotationError?.message ?? "", /Not latest signers/);
+
+  const staleRetentionError = await t.throwsAsync(
+    contract.call(authModule, "set_old_key_retention_from_gateway", {
+      old_key_retention: 2,
+      expected_epoch: 1,
+    })
+  );
+  t.regex(staleRetentionError?.message ?? "", /Not latest signers/);
+  t.is(await authModule.view("current_epoch"), 2);
+  t.is(await authModule.view("old_key_retention"), 1);
+
+  const gatewayError = await t.throwsAsync(
+    john.call(authModule, "set_old_key_retention_from_gateway", {
+      old_key_retention: 2,
+      expected_epoch: 2,
+    })
+  );
+  // t.log(gatewayError?.message);
+  t.not(gatewayError, undefined); // Owner only
+});
+
+test("Gateway - should approve contract call from a Borsh-encoded batch", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const payloadHash = ethers.utils.keccak256("0x1234");
+  const commandId = Utils.getRandomID();
+  const sourceChain = "Polygon";
+  const sourceAddress = "address0x123";
+  const sourceTxHash = ethers.utils.keccak256("0x123abc123abc");
+  const sourceEventIndex = 17;
+
+  const transferId = Utils.getRandomID();
+  const newOperators = sortBy(wallets.slice(0, 2), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+
+  const approveData = Utils.buildBorshCommandBatch(
+    CHAIN_ID,
+    [commandId, transferId],
+    ["approveContractCall", "transferOperatorship"],
+    [
+      Utils.getBorshApproveContractCall(
+        sourceChain,
+        sourceAddress,
+        contract.accountId,
+        payloadHash,
+        sourceTxHash,
+        sourceEventIndex
+      ),
+      Utils.getBorshWeightedSigners(newOperators, [1, 1], 2).subarray(1),
+    ]
+  );
+
+  const approveInput = await Utils.getSignedBorshExecuteInput(
+    approveData,
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const result = await root.call(
+    contract,
+    "execute",
+    {
+      input: approveInput,
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.deepEqual(result, [true, true]);
+
+  const isApproved = await contract.view("is_contract_call_approved", {
+    command_id: commandId,
+    source_chain: sourceChain,
+    source_address: sourceAddress,
+    contract_address: contract.accountId,
+    payload_hash: payloadHash,
+  });
+
+  t.is(isApproved, true);
+
+  const currentOperators: any = await contract.view("current_operators", {});
+
+  t.deepEqual(
+    currentOperators.operators,
+    newOperators.map(Utils.getOperatorKeyString)
+  );
+
+  // ABI-encoded params are not accepted in a Borsh batch
+  const abiData = Utils.buildBorshCommandBatch(
+    CHAIN_ID,
+    [Utils.getRandomID()],
+    ["approveContractCall"],
+    [
+      await Utils.getApproveContractCall(
+        sourceChain,
+        sourceAddress,
+        contract.accountId,
+        payloadHash,
+        sourceTxHash,
+        sourceEventIndex
+      ),
+    ]
+  );
+
+  const abiError = await t.throwsAsync(
+    root.call(
+      contract,
+      "execute",
+      {
+        input: await Utils.getSignedBorshExecuteInput(
+          abiData,
+          newOperators,
+          [1, 1],
+          2,
+          newOperators
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+  // t.log(abiError?.message);
+  t.not(abiError, undefined); // Error decoding Borsh-encoded params
+});
+
+test("Gateway - should reject a Borsh-encoded batch signed as an ABI batch", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const data = Utils.buildBorshCommandBatch(
+    CHAIN_ID,
+    [Utils.getRandomID()],
+    ["approveContractCall"],
+    [
+      Utils.getBorshApproveContractCall(
+        "Polygon",
+        "address0x123",
+        contract.accountId,
+        ethers.utils.keccak256("0x1234"),
+        ethers.utils.keccak256("0x123abc123abc"),
+        17
+      ),
+    ]
+  );
+
+  const proof = await Utils.getBorshWeightedSignaturesProof(
+    ethers.utils.keccak256(data),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const input = ethers.utils.hexlify(
+    Buffer.concat([
+      Buffer.from([Utils.BORSH_ENCODING_VERSION]),
+      Utils.borshBytes(data),
+      Utils.borshBytes(proof),
+    ])
+  );
+
+  const error = await t.throwsAsync(
+    root.call(contract, "execute", { input }, { attachedDeposit: "0" })
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Malformed signers
+});
+
+test("Gateway - should accept base64 encoded input, ids and hashes", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const payload = ethers.utils.defaultAbiCoder.encode(
+    ["address"],
+    [owner.address]
+  );
+  const payloadHash = ethers.utils.keccak256(payload);
+  const commandId = Utils.getRandomID();
+  const sourceChain = "Polygon";
+  const sourceAddress = "address0x123";
+  const sourceTxHash = ethers.utils.keccak256("0x123abc123abc");
+  const sourceEventIndex = 17;
+
+  const approveData = await Utils.buildCommandBatch(
+    CHAIN_ID,
+    [commandId],
+    ["approveContractCall"],
+    [
+      await Utils.getApproveContractCall(
+        sourceChain,
+        sourceAddress,
+        contract.accountId,
+        payloadHash,
+        sourceTxHash,
+        sourceEventIndex
+      ),
+    ]
+  );
+
+  const approveInput = await Utils.getSignedWeightedExecuteInput(
+    approveData,
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const result = await root.call(
+    contract,
+    "execute",
+    {
+      input: Utils.toBase64(approveInput),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.deepEqual(result, [true]);
+
+  const isApprovedBefore = await contract.view("is_contract_call_approved", {
+    command_id: Utils.toBase64(commandId),
+    source_chain: sourceChain,
+    source_address: sourceAddress,
+    contract_address: contract.accountId,
+    payload_hash: payloadHash,
+  });
+
+  t.is(isApprovedBefore, true);
+
+  const bareHashError = await t.throwsAsync(
+    contract.view("is_contract_call_approved", {
+      command_id: Utils.toBase64(commandId),
+      source_chain: sourceChain,
+      source_address: sourceAddress,
+      contract_address: contract.accountId,
+      payload_hash: payloadHash.slice(2),
+    })
+  );
+
+  t.not(bareHashError, undefined); // Invalid hex string: missing 0x prefix
+
+  const isValid = await contract.call(
+    contract,
+    "validate_contract_call",
+    {
+      command_id: Utils.toBase64(commandId),
+      source_chain: sourceChain,
+      source_address: sourceAddress,
+      payload_hash: Utils.toBase64(payloadHash),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(isValid, true);
+
+  const event: { payload_hash: string; payload: string } = await contract.call(
+    contract,
+    "call_contract",
+    {
+      destination_chain: sourceChain,
+      destination_contract_address: sourceAddress,
+      payload: Utils.toBase64(payload),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(event.payload_hash, payloadHash);
+  t.is(event.payload, payload);
+
+  const bareHexError = await t.throwsAsync(
+    contract.call(
+      contract,
+      "call_contract",
+      {
+        destination_chain: sourceChain,
+        destination_contract_address: sourceAddress,
+        payload: payload.slice(2),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  t.not(bareHexError, undefined); // Invalid hex string: missing 0x prefix
+
+  const unpaddedError = await t.throwsAsync(
+    contract.call(
+      contract,
+      "call_contract",
+      {
+        destination_chain: sourceChain,
+        destination_contract_address: sourceAddress,
+        payload: Utils.toBase64("0x1234").replace(/=+$/, ""),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  t.not(unpaddedError, undefined); // Invalid base64 string: not padded
+});
+
+test("Gateway - should emit version 2.0.0 events next to version 1.0.0", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const commandId = Utils.getRandomID();
+  const payloadHash = ethers.utils.keccak256("0x1234");
+
+  const approveData = await Utils.buildCommandBatch(
+    CHAIN_ID,
+    [commandId],
+    ["approveContractCall"],
+    [
+      await Utils.getApproveContractCall(
+        "Polygon",
+        "address0x123",
+        contract.accountId,
+        payloadHash,
+        ethers.utils.keccak256("0x123abc123abc"),
+        17
+      ),
+    ]
+  );
+
+  const executeTx = await root.callRaw(
+    contract,
+    "execute",
+    {
+      input: await Utils.getSignedWeightedExecuteInput(
+        approveData,
+        operators,
+        operators.map(() => 1),
+        threshold,
+        operators.slice(0, threshold)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  const executeEvents = Utils.getAxelarEvents(executeTx);
+  const getEvent = (events: any[], event: string, version: string) =>
+    events.find((x) => x.event === event && x.version === version);
+
+  t.truthy(getEvent(executeEvents, "executed_event", "1.0.0"));
+  t.truthy(getEvent(executeEvents, "contract_call_approved_event", "1.0.0"));
+
+  const executed = getEvent(executeEvents, "executed_event", "2.0.0");
+
+  t.is(executed.data.command_id, commandId);
+  t.is(executed.data.selector, "approveContractCall");
+
+  const approved = getEvent(
+    executeEvents,
+    "contract_call_approved_event",
+    "2.0.0"
+  );
+
+  t.is(approved.data.command_id, commandId);
+  t.is(approved.data.payload_hash, payloadHash);
+  t.true(approved.data.approval_block_height > 0);
+
+  const params = await Utils.getTransferWeightedOperatorshipCommand(
+    Utils.getAddresses(wallets.slice(0, 3)),
+    [1, 1, 1],
+    2
+  );
+
+  const transferTx = await root.callRaw(
+    contract,
+    "transfer_operatorship",
+    { params },
+    { attachedDeposit: "0" }
+  );
+
+  const transferEvents = Utils.getAxelarEvents(transferTx);
+
+  const transferredV1 = getEvent(
+    transferEvents,
+    "operatorship_transferred_event",
+    "1.0.0"
+  );
+
+  // Version 1.0.0 keeps its original shape
+  t.deepEqual(Object.keys(transferredV1.data).sort(), [
+    "new_operators",
+    "new_threshold",
+    "new_weights",
+  ]);
+  t.deepEqual(transferredV1.data.new_weights, [1, 1, 1]);
+  t.is(transferredV1.data.new_threshold, 2);
+
+  const transferred = getEvent(
+    transferEvents,
+    "operatorship_transferred_event",
+    "2.0.0"
+  );
+
+  t.is(transferred.data.epoch, previousOperators.length + 2);
+  t.is(transferred.data.operators_hash, ethers.utils.keccak256(params));
+  t.is(transferred.data.new_threshold, "2");
+});
+
+test("Gateway - should execute admin commands only with a proof from the current operators", async (t) => {
+  const { root, contract, john } = t.context.accounts;
+
+  const executeCommands = async (
+    commands: string[],
+    params: string[],
+    signers: SignerWithAddress[]
+  ) => {
+    const data = await Utils.buildCommandBatch(
+      CHAIN_ID,
+      commands.map(() => Utils.getRandomID()),
+      commands,
+      params
+    );
+
+    return root.call(
+      contract,
+      "execute",
+      {
+        input: await Utils.getSignedWeightedExecuteInput(
+          data,
+          signers,
+          signers.map(() => 1),
+          threshold,
+          signers.slice(0, threshold)
+        ),
+      },
+      { attachedDeposit: "0" }
+    );
+  };
+
+  const previous = previousOperators[previousOperators.length - 1];
+
+  t.deepEqual(await executeCommands(["pause"], ["0x"], previous), []);
+  t.is(await contract.view("paus_is_paused"), false);
+
+  t.deepEqual(await executeCommands(["pause"], ["0x"], operators), [true]);
+  t.is(await contract.view("paus_is_paused"), true);
+
+  const error = await t.throwsAsync(
+    root.call(contract, "call_contract", {
+      destination_chain: "Polygon",
+      destination_contract_address: "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88",
+      payload: "0x1234",
+    })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Disallowed while contract is paused
+
+  const approveContractCall = await Utils.getApproveContractCall(
+    "Polygon",
+    "address0x123",
+    contract.accountId,
+    ethers.utils.keccak256("0x1234"),
+    ethers.utils.keccak256("0x123abc123abc"),
+    17
+  );
+
+  // Approvals are held back while paused
+  t.deepEqual(
+    await executeCommands(["approveContractCall"], [approveContractCall], operators),
+    []
+  );
+
+  // Views keep answering while paused
+  t.is(
+    await contract.view("is_contract_call_approved", {
+      command_id: Utils.getRandomID(),
+      source_chain: "Polygon",
+      source_address: "address0x123",
+      contract_address: contract.accountId,
+      payload_hash: ethers.utils.keccak256("0x1234"),
+    }),
+    false
+  );
+
+  t.deepEqual(
+    await executeCommands(
+      ["unpause", "setRetention", "transferOwnership"],
+      [
+        "0x",
+        ethers.utils.defaultAbiCoder.encode(["uint256"], [8]),
+        ethers.utils.defaultAbiCoder.encode(["string"], [john.accountId]),
+      ],
+      operators
+    ),
+    [true, true, true]
+  );
+
+  t.is(await contract.view("paus_is_paused"), false);
+  t.is(await contract.view("old_key_retention"), 8);
+  t.is(await contract.view("own_get_owner"), john.accountId);
+
+  // Upgrades deploy code staged beforehand, by hash
+  t.deepEqual(
+    await executeCommands(
+      ["upgrade"],
+      [
+        ethers.utils.defaultAbiCoder.encode(
+          ["bytes32", "bytes"],
+          [ethers.utils.sha256("0x0061736d01000000"), "0x"]
+        ),
+      ],
+      operators
+    ),
+    [false]
+  );
+});
+
+test("Gateway - should keep the state when upgrading", async (t) => {
+  const { root, contract, john } = t.context.accounts;
+
+  const commandId = Utils.getRandomID();
+  const approval = {
+    command_id: commandId,
+    source_chain: "Polygon",
+    source_address: "address0x123",
+    contract_address: contract.accountId,
+    payload_hash: ethers.utils.keccak256("0x1234"),
+  };
+
+  const executeCommand = async (command: string, params: string) =>
+    root.callRaw(
+      contract,
+      "execute",
+      {
+        input: await Utils.getSignedWeightedExecuteInput(
+          await Utils.buildCommandBatch(
+            CHAIN_ID,
+            [command === "approveContractCall" ? commandId : Utils.getRandomID()],
+            [command],
+            [params]
+          ),
+          operators,
+          operators.map(() => 1),
+          threshold,
+          operators.slice(0, threshold)
+        ),
+      },
+      { attachedDeposit: "0", gas: "300000000000000" }
+    );
+
+  await executeCommand(
+    "approveContractCall",
+    await Utils.getApproveContractCall(
+      approval.source_chain,
+      approval.source_address,
+      approval.contract_address,
+      approval.payload_hash,
+      ethers.utils.keccak256("0x123abc123abc"),
+      17
+    )
+  );
+
+  const code = fs.readFileSync(
+    path.join(__dirname, "../../dist/axelar_cgp_near.wasm")
+  );
+
+  const codeHash = await root.call(
+    contract,
+    "stage_upgrade",
+    { code: code.toString("base64") },
+    { attachedDeposit: NEAR.parse("10 N").toJSON(), gas: "300000000000000" }
+  );
+
+  t.is(codeHash, ethers.utils.sha256(code));
+
+  const upgradeTx = await executeCommand(
+    "upgrade",
+    ethers.utils.defaultAbiCoder.encode(["bytes32", "bytes"], [codeHash, "0x"])
+  );
+
+  t.deepEqual(upgradeTx.parseResult(), [true]);
+  t.is(await contract.view("is_upgrade_staged", { code_hash: codeHash }), false);
+  t.deepEqual(
+    Utils.getAxelarEvents(upgradeTx).find(
+      (event) => event.event === "upgraded_event"
+    )?.data,
+    { code_hash: codeHash, has_migration_args: false }
+  );
+
+  // migrate runs on the new code even without migration arguments
+  t.true(
+    upgradeTx.result.receipts_outcome.every(
+      (receipt: any) => receipt.outcome.status.Failure === undefined
+    )
+  );
+  t.is(await contract.view("is_contract_call_approved", approval), true);
+  t.is(await contract.view("current_epoch"), previousOperators.length + 1);
+
+  const error = await t.throwsAsync(john.call(contract, "migrate", {}));
+  // t.log(error?.message);
+  t.not(error, undefined); // Method migrate is private
+});
+
+test("Gateway - should migrate the state of version 1.0.0", async (t) => {
+  const { root } = t.context.accounts;
+
+  const legacy = await root.createSubAccount("legacy");
+
+  await legacy.deploy(
+    path.join(__dirname, "../../dist/axelar_cgp_near.wasm")
+  );
+
+  // Version 1.0.0 only stored the hash of each operator set
+  const hashes = [...previousOperators, operators].map((operatorSet) =>
+    ethers.utils.keccak256(
+      ethers.utils.defaultAbiCoder.encode(
+        ["address[]", "uint256[]", "uint256"],
+        [Utils.getAddresses(operatorSet), operatorSet.map(() => 1), threshold]
+      )
+    )
+  );
+
+  for (const [i, hash] of hashes.entries()) {
+    const epoch = Utils.borshU64(i + 1);
+    const hashBytes = Buffer.from(ethers.utils.arrayify(hash));
+
+    await legacy.updateData(
+      Buffer.concat([Buffer.from("hash_for_epoch"), epoch]),
+      hashBytes
+    );
+    await legacy.updateData(
+      Buffer.concat([Buffer.from("epoch_for_hash"), hashBytes]),
+      epoch
+    );
+  }
+
+  await legacy.updateData(
+    Buffer.from("STATE"),
+    Buffer.concat([
+      Utils.borshU64(hashes.length),
+      Utils.borshString("hash_for_epoch"),
+      Utils.borshString("epoch_for_hash"),
+      Buffer.from(ethers.utils.arrayify(ethers.utils.id("command-executed"))),
+      Buffer.from(
+        ethers.utils.arrayify(ethers.utils.id("contract-call-approved"))
+      ),
+      Utils.borshString("bool_state"),
+    ])
+  );
+
+  await legacy.call(legacy, "migrate", {});
+
+  t.is(await legacy.view("current_epoch"), hashes.length);
+  t.is(await legacy.view("epoch_for_hash", { hash: hashes[0] }), 1);
+
+  // The operator sets of the migrated epochs are unknown
+  t.is(await legacy.view("current_operators", {}), null);
+  t.is(await legacy.view("operators_for_epoch", { epoch: 1 }), null);
+
+  const unknownError = await t.throwsAsync(
+    legacy.view("operators_for_epoch", { epoch: hashes.length + 1 })
+  );
+  // t.log(unknownError?.message);
+  t.not(unknownError, undefined); // Unknown epoch
+
+  // The migrated operators can still rotate to a new operator set
+  const newOperators = sortBy(wallets.slice(0, 2), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+
+  const result = await root.call(
+    legacy,
+    "execute",
+    {
+      input: await Utils.getSignedWeightedExecuteInput(
+        await Utils.buildCommandBatch(
+          CHAIN_ID,
+          [Utils.getRandomID()],
+          ["transferOperatorship"],
+          [
+            await Utils.getTransferWeightedOperatorshipCommand(
+              Utils.getAddresses(newOperators),
+              [1, 1],
+              2
+            ),
+          ]
+        ),
+        operators,
+        operators.map(() => 1),
+        threshold,
+        operators.slice(0, threshold)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.deepEqual(result, [true]);
+
+  const currentOperators: any = await legacy.view("current_operators", {});
+
+  t.deepEqual(
+    currentOperators.operators,
+    newOperators.map(Utils.getOperatorKeyString)
+  );
+});
+
+test("Gateway - should transfer ownership in two steps with cancellation and expiry", async (t) => {
+  const { root, contract, john } = t.context.accounts;
+
+  const alice = await root.createSubAccount("alice");
+
+  const notOwnerError = await t.throwsAsync(
+    john.call(
+      contract,
+      "own_propose_owner",
+      { account_id: john.accountId },
+      { attachedDeposit: "1" }
+    )
+  );
+  // t.log(notOwnerError?.message);
+  t.not(notOwnerError, undefined); // Owner only
+
+  // A proposal that expires right away cannot be accepted
+  await root.call(
+    contract,
+    "own_propose_owner_with_expiry",
+    { account_id: john.accountId, expires_in: 1 },
+    { attachedDeposit: "1" }
+  );
+
+  t.is(await contract.view("own_get_proposed_owner"), null);
+  t.is(await contract.view("own_get_ownership_proposal"), null);
+
+  const expiredError = await t.throwsAsync(
+    john.call(contract, "own_accept_owner", {}, { attachedDeposit: "1" })
+  );
+  // t.log(expiredError?.message);
+  t.not(expiredError, undefined); // No proposed owner
+
+  await root.call(
+    contract,
+    "own_propose_owner",
+    { account_id: john.accountId },
+    { attachedDeposit: "1" }
+  );
+
+  t.is(await contract.view("own_get_proposed_owner"), john.accountId);
+
+  const proposal: any = await contract.view("own_get_ownership_proposal");
+
+  t.is(proposal.account_id, john.accountId);
+  t.true(proposal.expires_at > 0);
+
+  await root.call(
+    contract,
+    "own_cancel_proposed_owner",
+    {},
+    { attachedDeposit: "1" }
+  );
+
+  t.is(await contract.view("own_get_proposed_owner"), null);
+
+  // Proposing no account cancels the pending proposal as well
+  await root.call(
+    contract,
+    "own_propose_owner",
+    { account_id: john.accountId },
+    { attachedDeposit: "1" }
+  );
+  await root.call(
+    contract,
+    "own_propose_owner",
+    { account_id: null },
+    { attachedDeposit: "1" }
+  );
+
+  t.is(await contract.view("own_get_proposed_owner"), null);
+
+  const cancelledError = await t.throwsAsync(
+    john.call(contract, "own_accept_owner", {}, { attachedDeposit: "1" })
+  );
+  // t.log(cancelledError?.message);
+  t.not(cancelledError, undefined); // No proposed owner
+
+  await root.call(
+    contract,
+    "own_propose_owner",
+    { account_id: john.accountId },
+    { attachedDeposit: "1" }
+  );
+
+  const otherError = await t.throwsAsync(
+    alice.call(contract, "own_accept_owner", {}, { attachedDeposit: "1" })
+  );
+  // t.log(otherError?.message);
+  t.not(otherError, undefined); // Proposed owner only
+
+  const result = await john.callRaw(
+    contract,
+    "own_accept_owner",
+    {},
+    { attachedDeposit: "1" }
+  );
+
+  t.true(
+    Utils.getAxelarEvents(result).some(
+      (event: any) =>
+        event.event === "ownership_transferred_event" &&
+        event.data.new_owner === john.accountId
+    )
+  );
+  // Only the gateway event is emitted, not the `x-own` transfer event
+  t.false(
+    result.result.receipts_outcome
+      .flatMap((receipt: any) => receipt.outcome.logs)
+      .some((log: string) => log.includes("x-own"))
+  );
+  t.is(await contract.view("own_get_owner"), john.accountId);
+  t.is(await contract.view("own_get_proposed_owner"), null);
+
+  await john.call(
+    contract,
+    "own_propose_owner",
+    { account_id: alice.accountId },
+    { attachedDeposit: "1" }
+  );
+
+  const renounceResult = await john.callRaw(
+    contract,
+    "own_renounce_owner",
+    {},
+    { attachedDeposit: "1" }
+  );
+
+  t.true(
+    Utils.getAxelarEvents(renounceResult).some(
+      (event: any) =>
+        event.event === "ownership_transferred_event" &&
+        event.data.previous_owner === john.accountId &&
+        event.data.new_owner === ""
+    )
+  );
+  t.is(await contract.view("own_get_owner"), null);
+  t.is(await contract.view("own_get_proposed_owner"), null);
+
+  const renouncedError = await t.throwsAsync(
+    john.call(
+      contract,
+      "own_propose_owner",
+      { account_id: john.accountId },
+      { attachedDeposit: "1" }
+    )
+  );
+  // t.log(renouncedError?.message);
+  t.not(renouncedError, undefined); // Owner only
+});
+
+test("Gateway - should emit an admin action event for every owner call", async (t) => {
+  const { root, contract, john } = t.context.accounts;
+
+  t.is(await contract.view("admin_action_count"), 0);
+  t.is(await contract.view("admin_override_count"), 0);
+
+  const payloadHash = ethers.utils.keccak256("0x1234");
+  const args = {
+    params: await Utils.getApproveContractCall(
+      "Polygon",
+      "address0x123",
+      contract.accountId,
+      payloadHash,
+      ethers.utils.keccak256("0x123abc123abc"),
+      17
+    ),
+    command_id: Utils.getRandomID(),
+  };
+
+  const result = await root.callRaw(contract, "approve_contract_call", args);
+
+  const adminAction = Utils.getAxelarEvents(result).find(
+    (event: any) => event.event === "admin_action_event"
+  );
+
+  t.deepEqual(adminAction.data, {
+    method: "approve_contract_call",
+    caller: root.accountId,
+    args_hash: ethers.utils.keccak256(
+      ethers.utils.toUtf8Bytes(JSON.stringify(args))
+    ),
+    count: 1,
+  });
+
+  const adminOverride = Utils.getAxelarEvents(result).find(
+    (event: any) => event.event === "admin_override_event"
+  );
+
+  t.deepEqual(adminOverride.data, {
+    method: "approve_contract_call",
+    caller: root.accountId,
+    count: 1,
+  });
+
+  const error = await t.throwsAsync(
+    john.call(contract, "set_old_key_retention", { old_key_retention: 8 })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Owner only
+
+  // Changing a setting is an admin action, but not an override of the operators
+  const settingResult = await root.callRaw(contract, "set_old_key_retention", {
+    old_key_retention: 8,
+  });
+
+  t.false(
+    Utils.getAxelarEvents(settingResult).some(
+      (event: any) => event.event === "admin_override_event"
+    )
+  );
+  t.is(await contract.view("admin_action_count"), 2);
+  t.is(await contract.view("admin_override_count"), 1);
+
+  const transferResult = await root.callRaw(contract, "transfer_operatorship", {
+    params: await Utils.getTransferWeightedOperatorshipCommand(
+      Utils.getAddresses([owner]),
+      [1],
+      1
+    ),
+  });
+
+  t.true(
+    Utils.getAxelarEvents(transferResult).some(
+      (event: any) =>
+        event.event === "admin_override_event" &&
+        event.data.method === "transfer_operatorship" &&
+        event.data.count === 2
+    )
+  );
+  t.is(await contract.view("admin_action_count"), 3);
+  t.is(await contract.view("admin_override_count"), 2);
+});
+
+test("Gateway - should only approve operator-signed calls once admin approvals are disabled", async (t) => {
+  const { root, contract } = t.context.accounts;
+
+  const getParams = async () =>
+    Utils.getApproveContractCall(
+      "Polygon",
+      "address0x123",
+      contract.accountId,
+      ethers.utils.keccak256("0x1234"),
+      ethers.utils.keccak256("0x123abc123abc"),
+      17
+    );
+
+  const adminCommandId = Utils.getRandomID();
+
+  await root.call(contract, "approve_contract_call", {
+    params: await getParams(),
+    command_id: adminCommandId,
+  });
+
+  // Owner approvals cannot collide with operator-signed commands
+  t.is(
+    await contract.view("is_command_executed", { command_id: adminCommandId }),
+    true
+  );
+
+  const data = await Utils.buildCommandBatch(
+    CHAIN_ID,
+    [adminCommandId],
+    ["approveContractCall"],
+    [await getParams()]
+  );
+
+  const collidingResult = await root.call(
+    contract,
+    "execute",
+    {
+      input: await Utils.getSignedWeightedExecuteInput(
+        data,
+        operators,
+        operators.map(() => 1),
+        threshold,
+        operators.slice(0, threshold)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.deepEqual(collidingResult, []);
+
+  t.is(await contract.view("admin_approvals_disabled"), false);
+
+  // An owner approval made by an earlier version of the gateway, which did not mark it executed
+  const earlierCommandId = Utils.getRandomID();
+
+  t.is(
+    await contract.view("is_command_executed", {
+      command_id: earlierCommandId,
+    }),
+    false
+  );
+
+  const disableResult = await root.callRaw(contract, "disable_admin_approvals", {
+    command_ids: [earlierCommandId],
+  });
+
+  t.true(
+    Utils.getAxelarEvents(disableResult).some(
+      (event: any) =>
+        event.event === "admin_approvals_disabled_event" &&
+        event.data.command_ids.length === 1 &&
+        event.data.command_ids[0] === earlierCommandId
+    )
+  );
+  t.is(await contract.view("admin_approvals_disabled"), true);
+  t.is(
+    await contract.view("is_command_executed", {
+      command_id: earlierCommandId,
+    }),
+    true
+  );
+
+  const error = await t.throwsAsync(
+    root.call(contract, "approve_contract_call", {
+      params: await getParams(),
+      command_id: Utils.getRandomID(),
+    })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Admin approvals disabled
+
+  const disabledError = await t.throwsAsync(
+    root.call(contract, "disable_admin_approvals", {})
+  );
+  // t.log(disabledError?.message);
+  t.not(disabledError, undefined); // Admin approvals already disabled
+
+  const operatorData = await Utils.buildCommandBatch(
+    CHAIN_ID,
+    [earlierCommandId, Utils.getRandomID()],
+    ["approveContractCall", "approveContractCall"],
+    [await getParams(), await getParams()]
+  );
+
+  const result = await root.call(
+    contract,
+    "execute",
+    {
+      input: await Utils.getSignedWeightedExecuteInput(
+        operatorData,
+        operators,
+        operators.map(() => 1),
+        threshold,
+        operators.slice(0, threshold)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  // The earlier owner approval is skipped
+  t.deepEqual(result, [true]);
+});
+
+test("Gateway - call contract event will emit", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const chain = "Polygon";
+  const destination = "0xb7900E8Ec64A1D1315B6D4017d4b1dcd36E6Ea88";
+  const payload = ethers.utils.defaultAbiCoder.encode(
+    ["address", "address"],
+    [wallets[1].address, wallets[2].address]
+  );
+
+  const event: {
+    address: string;
+    destination_chain: string;
+    destination_contract_address: string;
+    payload_hash: string;
+    payload: string;
+  } = await contract.call(
+    contract,
+    "call_contract",
+    {
+      destination_chain: chain,
+      destination_contract_address: destination,
+      payload,
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(event.address, contract.accountId);
+  t.is(event.destination_chain, chain);
+  t.is(event.destination_contract_address, destination);
+  t.is(event.payload_hash, ethers.utils.keccak256(payload));
+  t.is(event.payload, payload);
+});
+
+// Amplifier Gateway Tests
+
+test("Amplifier - should approve and validate messages", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const message = {
+    source_chain: "Polygon",
+    message_id: "0x123abc123abc-1",
+    source_address: "address0x123",
+    contract_address: contract.accountId,
+    payload_hash: ethers.utils.keccak256("0x1234"),
+  };
+
+  const proof = await Utils.getWeightedSignaturesProof(
+    Utils.getApproveMessagesData([message]),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  await root.call(
+    contract,
+    "approve_messages",
+    { messages: [message], proof },
+    { attachedDeposit: "0" }
+  );
+
+  const isApprovedBefore = await contract.view("is_message_approved", message);
+
+  t.is(isApprovedBefore, true);
+
+  const isValid = await contract.call(
+    contract,
+    "validate_message",
+    {
+      source_chain: message.source_chain,
+      message_id: message.message_id,
+      source_address: message.source_address,
+      payload_hash: message.payload_hash,
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(isValid, true);
+
+  const isApprovedAfter = await contract.view("is_message_approved", message);
+
+  t.is(isApprovedAfter, false);
+
+  const isExecuted = await contract.view("is_message_executed", {
+    source_chain: message.source_chain,
+    message_id: message.message_id,
+  });
+
+  t.is(isExecuted, true);
+});
+
+test("Amplifier - should migrate from the legacy to the NEAR signing domain", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const domainSeparator = Utils.getDomainSeparator("near", contract.accountId);
+
+  t.is(await contract.view("domain_separator", {}), domainSeparator);
+  t.is(await contract.view("signing_domain", {}), "Legacy");
+
+  const keys = Utils.sortOperators([
+    KeyPair.fromRandom("ed25519"),
+    KeyPair.fromRandom("ed25519"),
+  ]) as KeyPair[];
+
+  await root.call(
+    contract,
+    "transfer_operatorship",
+    {
+      params: ethers.utils.hexlify(
+        Utils.getBorshWeightedSigners(keys, [1, 1], 2)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  const approve = (id: string, domain: "legacy" | "near") => {
+    const message = {
+      source_chain: "Polygon",
+      message_id: id,
+      source_address: "address0x123",
+      contract_address: contract.accountId,
+      payload_hash: ethers.utils.keccak256("0x1234"),
+    };
+
+    const hash = ethers.utils.keccak256(
+      Utils.getApproveMessagesData([message])
+    );
+    const signed =
+      domain === "legacy"
+        ? ethers.utils.hashMessage(ethers.utils.arrayify(hash))
+        : Utils.getNearSignedMessageHash(domainSeparator, hash);
+
+    return root.call(
+      contract,
+      "approve_messages",
+      {
+        messages: [message],
+        proof: Utils.getEd25519BorshSignaturesProof(
+          signed,
+          keys,
+          [1, 1],
+          2,
+          keys
+        ),
+      },
+      { attachedDeposit: "0" }
+    );
+  };
+
+  const setSigningDomain = (signing_domain: string) =>
+    root.call(
+      contract,
+      "set_signing_domain",
+      { signing_domain },
+      { attachedDeposit: "0" }
+    );
+
+  await approve("legacy-1", "legacy");
+
+  const nearBeforeMigration = await t.throwsAsync(approve("near-1", "near"));
+
+  t.not(nearBeforeMigration, undefined); // Malformed signers
+
+  // Both domains are accepted while relayers switch over
+  await setSigningDomain("Transitional");
+
+  await approve("legacy-2", "legacy");
+  await approve("near-2", "near");
+
+  await setSigningDomain("Near");
+
+  t.is(await contract.view("signing_domain", {}), "Near");
+
+  await approve("near-3", "near");
+
+  const legacyAfterMigration = await t.throwsAsync(
+    approve("legacy-3", "legacy")
+  );
+
+  // t.log(legacyAfterMigration?.message); // uncomment to see the error message
+
+  t.not(legacyAfterMigration, undefined); // Malformed signers
+});
+
+test("Amplifier - should approve messages with a fixed key verifier", async (t) => {
+  const { root } = t.context.accounts;
+
+  const contract = await root.createSubAccount("fixed_key_gateway");
+
+  await contract.deploy(
+    path.join(__dirname, "../../dist/axelar_cgp_near.wasm")
+  );
+
+  await root.call(contract, "new", {
+    recent_operators: [],
+    proof_verifier: { FixedKey: owner.address.toLowerCase() },
+  });
+
+  t.deepEqual(await contract.view("proof_verifier"), {
+    FixedKey: owner.address.toLowerCase(),
+  });
+
+  const message = {
+    source_chain: "Polygon",
+    message_id: "0x123abc123abc-1",
+    source_address: "address0x123",
+    contract_address: contract.accountId,
+    payload_hash: ethers.utils.keccak256("0x1234"),
+  };
+
+  const hash = ethers.utils.arrayify(
+    ethers.utils.keccak256(Utils.getApproveMessagesData([message]))
+  );
+
+  // The fixed key is checked by inspect_proof too, not the operator sets of the gateway
+  const inspect = async (proof: string): Promise<any> =>
+    contract.view("inspect_proof", {
+      message_hash: ethers.utils.hashMessage(hash),
+      proof,
+    });
+
+  const valid = await inspect(await owner.signMessage(hash));
+
+  t.is(valid.failure, null);
+  t.is(valid.is_current, true);
+  t.is(valid.signatures[0].signer, owner.address.toLowerCase());
+
+  const otherKey = await inspect(await operators[0].signMessage(hash));
+
+  t.is(otherKey.failure, "Invalid signer");
+  t.is(otherKey.signatures[0].signer, operators[0].address.toLowerCase());
+  t.is(otherKey.signatures[0].operator_index, null);
+
+  t.is((await inspect("0x1234")).failure, "Invalid signature length");
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "approve_messages",
+      { messages: [message], proof: await operators[0].signMessage(hash) },
+      { attachedDeposit: "0" }
+    )
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // signed by another key
+
+  await root.call(
+    contract,
+    "approve_messages",
+    { messages: [message], proof: await owner.signMessage(hash) },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(await contract.view("is_message_approved", message), true);
+});
+
+test("Amplifier - should reject messages with an invalid proof", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const message = {
+    source_chain: "Polygon",
+    message_id: "0x123abc123abc-1",
+    source_address: "address0x123",
+    contract_address: contract.accountId,
+    payload_hash: ethers.utils.keccak256("0x1234"),
+  };
+
+  const proof = await Utils.getWeightedSignaturesProof(
+    Utils.getApproveMessagesData([{ ...message, message_id: "other" }]),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "approve_messages",
+      { messages: [message], proof },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Malformed signers
+});
+
+test("Amplifier - should rotate signers with a proof from the latest signers", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const newOperators = sortBy(wallets.slice(0, 3), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+  const newSigners = {
+    operators: Utils.getAddresses(newOperators),
+    weights: newOperators.map(() => 1),
+    threshold: 2,
+  };
+
+  const proof = await Utils.getWeightedSignaturesProof(
+    Utils.getRotateSignersData(
+      newSigners.operators,
+      newSigners.weights,
+      newSigners.threshold
+    ),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const didRotate = await root.call(
+    contract,
+    "rotate_signers",
+    { new_signers: newSigners, proof },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(didRotate, true);
+
+  const data = "0x123abc123abc";
+
+  const isCurrentOperators = await contract.view("validate_proof", {
+    message_hash: ethers.utils.hashMessage(
+      ethers.utils.arrayify(ethers.utils.keccak256(data))
+    ),
+    proof: await Utils.getWeightedSignaturesProof(
+      data,
+      newOperators,
+      newSigners.weights,
+      newSigners.threshold,
+      newOperators.slice(0, 2)
+    ),
+  });
+
+  t.is(isCurrentOperators, true);
+});
+
+test("Amplifier - should check proofs and rotate signers through a separate auth module", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const authModule = await deployAuthModule(root, contract);
+
+  t.is(await contract.view("auth_module"), contract.accountId);
+
+  const error = await t.throwsAsync(
+    contract.call(contract, "set_auth_module", {
+      auth_module: authModule.accountId,
+    })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // only the owner can switch the auth module
+
+  await root.call(contract, "set_auth_module", {
+    auth_module: authModule.accountId,
+  });
+
+  t.is(await contract.view("auth_module"), authModule.accountId);
+
+  const message = {
+    source_chain: "Polygon",
+    message_id: "0x123abc123abc-1",
+    source_address: "address0x123",
+    contract_address: contract.accountId,
+    payload_hash: ethers.utils.keccak256("0x1234"),
+  };
+
+  const invalidProof = await Utils.getWeightedSignaturesProof(
+    Utils.getApproveMessagesData([message]),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    wallets.slice(0, threshold)
+  );
+
+  const invalidError = await t.throwsAsync(
+    root.call(
+      contract,
+      "approve_messages",
+      { messages: [message], proof: invalidProof },
+      { attachedDeposit: "0" }
+    )
+  );
+  // t.log(invalidError?.message);
+  t.not(invalidError, undefined); // the auth module rejects the proof
+
+  t.is(await contract.view("is_message_approved", message), false);
+
+  const proof = await Utils.getWeightedSignaturesProof(
+    Utils.getApproveMessagesData([message]),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  await root.call(
+    contract,
+    "approve_messages",
+    { messages: [message], proof },
+    { attachedDeposit: "0", gas: "300000000000000" }
+  );
+
+  t.is(await contract.view("is_message_approved", message), true);
+
+  const newOperators = sortBy(wallets.slice(0, 3), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+  const newSigners = {
+    operators: Utils.getAddresses(newOperators),
+    weights: newOperators.map(() => 1),
+    threshold: 2,
+  };
+
+  const rotateProof = await Utils.getWeightedSignaturesProof(
+    Utils.getRotateSignersData(
+      newSigners.operators,
+      newSigners.weights,
+      newSigners.threshold
+    ),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const didRotate = await root.call(
+    contract,
+    "rotate_signers",
+    { new_signers: newSigners, proof: rotateProof },
+    { attachedDeposit: "0", gas: "300000000000000" }
+  );
+
+  t.is(didRotate, true);
+
+  // The operator sets live in the auth module, the gateway ones can no longer be read
+  t.is(await authModule.view("current_epoch"), 2);
+
+  const epochError = await t.throwsAsync(contract.view("current_epoch"));
+  // t.log(epochError?.message);
+  t.not(epochError, undefined); // Operators are managed by the auth module
+});
+
+test("Amplifier - should enforce the minimum rotation delay on signer rotations", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const lastRotation: number = await contract.view(
+    "last_rotation_timestamp",
+    {}
+  );
+
+  t.true(lastRotation > 0);
+
+  const oneDay = 24 * 60 * 60 * 1e9;
+
+  await root.call(
+    contract,
+    "set_minimum_rotation_delay",
+    { minimum_rotation_delay: oneDay },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(await contract.view("minimum_rotation_delay", {}), oneDay);
+
+  const newOperators = sortBy(wallets.slice(0, 3), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+  const newSigners = {
+    operators: Utils.getAddresses(newOperators),
+    weights: newOperators.map(() => 1),
+    threshold: 2,
+  };
+
+  const proof = await Utils.getWeightedSignaturesProof(
+    Utils.getRotateSignersData(
+      newSigners.operators,
+      newSigners.weights,
+      newSigners.threshold
+    ),
+    operators,
+    operators.map(() => 1),
+    threshold,
+    operators.slice(0, threshold)
+  );
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "rotate_signers",
+      { new_signers: newSigners, proof },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Insufficient rotation delay
+
+  // The owner can still rotate, e.g. in an emergency
+  const didTransferOperatorship = await root.call(
+    contract,
+    "transfer_operatorship",
+    {
+      params: await Utils.getTransferWeightedOperatorshipCommand(
+        newSigners.operators,
+        newSigners.weights,
+        newSigners.threshold
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(didTransferOperatorship, true);
+
+  t.true(
+    (await contract.view("last_rotation_timestamp", {})) > lastRotation
+  );
+});
+
+test("Amplifier - should let the owner manage the operators of a separate auth module", async (t) => {
+  const { contract, root, john } = t.context.accounts;
+
+  const authModule = await deployAuthModule(root, contract);
+
+  const noAuthModuleError = await t.throwsAsync(
+    root.call(contract, "set_auth_module_minimum_rotation_delay", {
+      minimum_rotation_delay: 1,
+    })
+  );
+  // t.log(noAuthModuleError?.message);
+  t.not(noAuthModuleError, undefined); // No auth module set
+
+  await root.call(contract, "set_auth_module", {
+    auth_module: authModule.accountId,
+  });
+
+  const oneDay = 24 * 60 * 60 * 1e9;
+
+  await root.call(
+    contract,
+    "set_auth_module_minimum_rotation_delay",
+    { minimum_rotation_delay: oneDay },
+    { attachedDeposit: "0", gas: "300000000000000" }
+  );
+
+  t.is(await authModule.view("minimum_rotation_delay", {}), oneDay);
+
+  const newOperators = sortBy(wallets.slice(0, 3), (wallet) =>
+    wallet.address.toLowerCase()
+  );
+  const params = await Utils.getTransferWeightedOperatorshipCommand(
+    Utils.getAddresses(newOperators),
+    newOperators.map(() => 1),
+    2
+  );
+
+  const ownerError = await t.throwsAsync(
+    john.call(contract, "transfer_auth_module_operatorship", { params })
+  );
+  // t.log(ownerError?.message);
+  t.not(ownerError, undefined); // Owner only
+
+  // The owner is not bound by the minimum rotation delay of the auth module
+  const didTransferOperatorship = await root.call(
+    contract,
+    "transfer_auth_module_operatorship",
+    { params },
+    { attachedDeposit: "0", gas: "300000000000000" }
+  );
+
+  t.is(didTransferOperatorship, true);
+  t.is(await authModule.view("current_epoch"), 2);
+
+  // Owner calls are overrides in the gateway and in the auth module
+  t.is(await contract.view("admin_override_count"), 1);
+  t.is(await authModule.view("admin_override_count"), 1);
+});
+
+test("Amplifier - should rotate back to previous signers with a new nonce", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const previous = previousOperators[previousOperators.length - 1];
+
+  const reusedSigners = {
+    operators: Utils.getAddresses(previous),
+    weights: previous.map(() => 1),
+    threshold,
+  };
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "rotate_signers",
+      {
+        new_signers: reusedSigners,
+        proof: await Utils.getWeightedSignaturesProof(
+          Utils.getRotateSignersData(
+            reusedSigners.operators,
+            reusedSigners.weights,
+            reusedSigners.threshold
+          ),
+          operators,
+          operators.map(() => 1),
+          threshold,
+          operators.slice(0, threshold)
+        ),
+      },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Duplicate operators
+
+  const nonce = Utils.getRandomID();
+
+  const didRotate = await root.call(
+    contract,
+    "rotate_signers",
+    {
+      new_signers: { ...reusedSigners, nonce },
+      proof: await Utils.getWeightedSignaturesProof(
+        Utils.getRotateSignersData(
+          reusedSigners.operators,
+          reusedSigners.weights,
+          reusedSigners.threshold,
+          nonce
+        ),
+        operators,
+        operators.map(() => 1),
+        threshold,
+        operators.slice(0, threshold)
+      ),
+    },
+    { attachedDeposit: "0" }
+  );
+
+  t.is(didRotate, true);
+
+  const registration: { epoch: number; nonce: string } = await contract.view(
+    "signers_for_hash",
+    {
+      hash: Utils.getWeightedSignersHash(
+        reusedSigners.operators,
+        reusedSigners.weights,
+        reusedSigners.threshold,
+        nonce
+      ),
+    }
+  );
+
+  t.is(registration.epoch, previousOperators.length + 2);
+  t.is(registration.nonce, nonce);
+});
+
+test("Amplifier - should not rotate signers with a proof from previous signers", async (t) => {
+  const { contract, root } = t.context.accounts;
+
+  const previous = previousOperators[previousOperators.length - 1];
+
+  const newSigners = {
+    operators: Utils.getAddresses(
+      sortBy(wallets.slice(0, 3), (wallet) => wallet.address.toLowerCase())
+    ),
+    weights: [1, 1, 1],
+    threshold: 2,
+  };
+
+  const proof = await Utils.getWeightedSignaturesProof(
+    Utils.getRotateSignersData(
+      newSigners.operators,
+      newSigners.weights,
+      newSigners.threshold
+    ),
+    previous,
+    previous.map(() => 1),
+    threshold,
+    previous.slice(0, threshold)
+  );
+
+  const error = await t.throwsAsync(
+    root.call(
+      contract,
+      "rotate_signers",
+      { new_signers: newSigners, proof },
+      { attachedDeposit: "0" }
+    )
+  );
+
+  // t.log(error?.message); // uncomment to see the error message
+
+  t.not(error, undefined); // Not latest signers
+});
+
+// Executable Near Contract tests
+test("Governance - should execute gateway proposals from the governance chain after the timelock", async (t) => {
+  const { root, contract } = t.context.accounts;
+
+  const governanceChain = "Axelarnet";
+  const governanceAddress = "axelar10d07y265gmmuvt4z0w9aw880jnsr700j7v9daj";
+
+  const governance = await root.createSubAccount("interchain_governance", {
+    initialBalance: NEAR.parse("3 N").toJSON(),
+  });
+
+  await governance.deploy(
+    path.join(__dirname, "../../interchain-governance/target/wasm32-unknown-unknown/release/interchain_governance.wasm")
+  );
+
+  await root.call(governance, "new", {
+    gateway_account_id: contract.accountId,
+    governance_chain: governanceChain,
+    governance_address: governanceAddress,
+    minimum_time_delay: 0,
+  });
+
+  const proposal = {
+    target: contract.accountId,
+    method: "own_accept_owner",
+    args: {},
+    deposit: "1",
+  };
+
+  const sendProposal = async (
+    command: number,
+    sourceAddress: string,
+    { target, method, args, deposit } = proposal
+  ) => {
+    const payload = Utils.getGovernanceProposalPayload(
+      command,
+      target,
+      method,
+      args,
+      deposit,
+      0
+    );
+    const commandId = Utils.getRandomID();
+
+    const approveData = await Utils.buildCommandBatch(
+      CHAIN_ID,
+      [commandId],
+      ["approveContractCall"],
+      [
+        await Utils.getApproveContractCall(
+          governanceChain,
+          sourceAddress,
+          governance.accountId,
+          ethers.utils.keccak256(payload),
+          ethers.utils.keccak256("0x123abc123abc"),
+          17
+        ),
+      ]
+    );
+
+    await root.call(
+      contract,
+      "execute",
+      {
+        input: await Utils.getSignedWeightedExecuteInput(
+          approveData,
+          operators,
+          operators.map(() => 1),
+          threshold,
+          operators.slice(0, threshold)
+        ),
+      },
+      { attachedDeposit: "0" }
+    );
+
+    await root.call(
+      governance,
+      "execute",
+      {
+        command_id: commandId,
+        source_chain: governanceChain,
+        source_address: sourceAddress,
+        payload,
+      },
+      { attachedDeposit: "0", gas: "100000000000000" }
+    );
+  };
+
+  const proposalArgs = {
+    target: proposal.target,
+    method: proposal.method,
+    args: Utils.toBase64(
+      ethers.utils.toUtf8Bytes(JSON.stringify(proposal.args))
+    ),
+    deposit: proposal.deposit,
+  };
+
+  const error = await t.throwsAsync(sendProposal(0, "axelar1notgovernance"));
+  // t.log(error?.message);
+  t.not(error, undefined); // Not governance
+
+  t.is(await governance.view("get_proposal_eta", proposalArgs), 0);
+
+  await sendProposal(0, governanceAddress);
+
+  const eta = await governance.view("get_proposal_eta", proposalArgs);
+
+  t.not(eta, 0);
+
+  const gasError = await t.throwsAsync(
+    root.call(governance, "execute_proposal", proposalArgs, {
+      gas: "30000000000000",
+    })
+  );
+  // t.log(gasError?.message);
+  t.not(gasError, undefined); // Insufficient gas
+
+  // Governance is not the proposed owner yet, so the call fails and the proposal stays scheduled
+  const failedTx = await root.callRaw(governance, "execute_proposal", proposalArgs, {
+    gas: "100000000000000",
+  });
+
+  t.is(failedTx.parseResult(), false);
+  t.false(
+    Utils.getAxelarEvents(failedTx).some(
+      (event) => event.event === "proposal_executed_event"
+    )
+  );
+  t.is(await governance.view("get_proposal_eta", proposalArgs), eta);
+
+  await root.call(
+    contract,
+    "own_propose_owner",
+    { account_id: governance.accountId },
+    { attachedDeposit: "1" }
+  );
+
+  t.is(
+    await root.call(governance, "execute_proposal", proposalArgs, {
+      gas: "100000000000000",
+    }),
+    true
+  );
+
+  t.is(await contract.view("own_get_owner"), governance.accountId);
+  t.is(await governance.view("get_proposal_eta", proposalArgs), 0);
+
+  const executedError = await t.throwsAsync(
+    root.call(governance, "execute_proposal", proposalArgs, {
+      gas: "100000000000000",
+    })
+  );
+  // t.log(executedError?.message);
+  t.not(executedError, undefined); // Proposal not scheduled
+
+  // Governance settings can only be changed by proposals
+  const settingsError = await t.throwsAsync(
+    root.call(governance, "set_minimum_time_delay", { minimum_time_delay: 60 })
+  );
+  // t.log(settingsError?.message);
+  t.not(settingsError, undefined); // Method is private
+
+  // The second proposal is only accepted from the new governance address
+  const newGovernanceAddress = "axelar1newgovernance";
+  const settingsProposals: [string, any][] = [
+    [
+      governanceAddress,
+      {
+        target: governance.accountId,
+        method: "set_governance",
+        args: {
+          governance_chain: governanceChain,
+          governance_address: newGovernanceAddress,
+        },
+        deposit: "0",
+      },
+    ],
+    [
+      newGovernanceAddress,
+      {
+        target: governance.accountId,
+        method: "set_minimum_time_delay",
+        args: { minimum_time_delay: 60 },
+        deposit: "0",
+      },
+    ],
+  ];
+
+  for (const [sourceAddress, settingsProposal] of settingsProposals) {
+    await sendProposal(0, sourceAddress, settingsProposal);
+
+    await root.call(
+      governance,
+      "execute_proposal",
+      {
+        ...settingsProposal,
+        args: Utils.toBase64(
+          ethers.utils.toUtf8Bytes(JSON.stringify(settingsProposal.args))
+        ),
+      },
+      { gas: "100000000000000" }
+    );
+  }
+
+  t.is(await governance.view("get_governance_address"), newGovernanceAddress);
+  t.is(await governance.view("get_minimum_time_delay"), 60);
+});
+
+test("Multisig - should execute owner-only gateway calls approved by the signers", async (t) => {
+  const { root, contract, john } = t.context.accounts;
+
+  const alice = await root.createSubAccount("alice");
+  const bob = await root.createSubAccount("bob");
+
+  // Two approvals after a day, or all three right away
+  const multisig = await deployMultisig(root, [alice, bob, john], 2, 3, 86400);
+
+  await root.call(
+    contract,
+    "own_propose_owner",
+    { account_id: multisig.accountId },
+    { attachedDeposit: "1" }
+  );
+
+  const toArgs = (args: object) =>
+    Utils.toBase64(ethers.utils.toUtf8Bytes(JSON.stringify(args)));
+
+  const acceptId: number = await alice.call(multisig, "propose", {
+    target: contract.accountId,
+    method: "own_accept_owner",
+    args: toArgs({}),
+    deposit: "1",
+  });
+
+  await bob.call(multisig, "approve", { proposal_id: acceptId });
+
+  const error = await t.throwsAsync(
+    alice.call(multisig, "execute", { proposal_id: acceptId }, { gas: "100000000000000" })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Timelock not ready
+
+  const rootError = await t.throwsAsync(
+    root.call(multisig, "approve", { proposal_id: acceptId })
+  );
+  // t.log(rootError?.message);
+  t.not(rootError, undefined); // Not a signer
+
+  // The emergency path skips the delay
+  await john.call(multisig, "approve", { proposal_id: acceptId });
+
+  const gasError = await t.throwsAsync(
+    alice.call(multisig, "execute", { proposal_id: acceptId }, { gas: "30000000000000" })
+  );
+  // t.log(gasError?.message);
+  t.not(gasError, undefined); // Insufficient gas
+
+  t.is(
+    await alice.call(multisig, "execute", { proposal_id: acceptId }, { gas: "100000000000000" }),
+    true
+  );
+
+  t.is(await contract.view("own_get_owner"), multisig.accountId);
+  t.is(await multisig.view("get_proposal", { proposal_id: acceptId }), null);
+
+  // A failing call keeps the proposal and its approvals
+  const invalidId: number = await bob.call(multisig, "propose", {
+    target: contract.accountId,
+    method: "set_old_key_retention",
+    args: toArgs({ old_key_retention: 0 }),
+  });
+
+  await alice.call(multisig, "approve", { proposal_id: invalidId });
+  await john.call(multisig, "approve", { proposal_id: invalidId });
+
+  const failedTx = await bob.callRaw(
+    multisig,
+    "execute",
+    { proposal_id: invalidId },
+    { gas: "100000000000000" }
+  );
+
+  t.is(failedTx.parseResult(), false);
+  t.false(
+    Utils.getAxelarEvents(failedTx).some(
+      (event) => event.event === "proposal_executed_event"
+    )
+  );
+
+  const invalidProposal: any = await multisig.view("get_proposal", {
+    proposal_id: invalidId,
+  });
+
+  t.is(invalidProposal.approvals.length, 3);
+
+  const retentionId: number = await bob.call(multisig, "propose", {
+    target: contract.accountId,
+    method: "set_old_key_retention",
+    args: toArgs({ old_key_retention: 8 }),
+  });
+
+  await alice.call(multisig, "approve", { proposal_id: retentionId });
+  await john.call(multisig, "approve", { proposal_id: retentionId });
+  await bob.call(multisig, "execute", { proposal_id: retentionId }, { gas: "100000000000000" });
+
+  t.is(await contract.view("old_key_retention"), 8);
+});
+
+test("Multisig - should execute proposals once their delay has passed", async (t) => {
+  const { root, john } = t.context.accounts;
+
+  const alice = await root.createSubAccount("alice");
+  const bob = await root.createSubAccount("bob");
+
+  const multisig = await deployMultisig(root, [alice, bob, john], 2, 3, 2);
+
+  const delayId: number = await alice.call(multisig, "propose", {
+    target: multisig.accountId,
+    method: "set_signers",
+    args: Utils.toBase64(
+      ethers.utils.toUtf8Bytes(
+        JSON.stringify({
+          signers: [alice.accountId, bob.accountId, john.accountId],
+          threshold: 2,
+          emergency_threshold: 3,
+          delay: 86400,
+        })
+      )
+    ),
+  });
+
+  await bob.call(multisig, "approve", { proposal_id: delayId });
+
+  const proposal: any = await multisig.view("get_proposal", { proposal_id: delayId });
+  t.true(proposal.eta > 0);
+
+  const error = await t.throwsAsync(
+    alice.call(multisig, "execute", { proposal_id: delayId }, { gas: "100000000000000" })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Timelock not ready
+
+  await new Promise((resolve) => setTimeout(resolve, 3000));
+
+  t.is(
+    await alice.call(multisig, "execute", { proposal_id: delayId }, { gas: "100000000000000" }),
+    true
+  );
+
+  t.is(await multisig.view("get_delay"), 86400);
+  t.is(await multisig.view("get_proposal", { proposal_id: delayId }), null);
+});
+
+test("Multisig - should start the delay over when the threshold changes", async (t) => {
+  const { root, john } = t.context.accounts;
+
+  const alice = await root.createSubAccount("alice");
+  const bob = await root.createSubAccount("bob");
+
+  const multisig = await deployMultisig(root, [alice, bob, john], 2, 3, 86400);
+
+  const setSignersArgs = (threshold: number) =>
+    Utils.toBase64(
+      ethers.utils.toUtf8Bytes(
+        JSON.stringify({
+          signers: [alice.accountId, bob.accountId, john.accountId],
+          threshold,
+          emergency_threshold: 3,
+          delay: 86400,
+        })
+      )
+    );
+
+  // Below the threshold, so its delay has not started
+  const pendingId: number = await alice.call(multisig, "propose", {
+    target: multisig.accountId,
+    method: "set_signers",
+    args: setSignersArgs(2),
+  });
+
+  const pending: any = await multisig.view("get_proposal", { proposal_id: pendingId });
+  t.is(pending.eta, 0);
+
+  const thresholdId: number = await alice.call(multisig, "propose", {
+    target: multisig.accountId,
+    method: "set_signers",
+    args: setSignersArgs(1),
+  });
+
+  await bob.call(multisig, "approve", { proposal_id: thresholdId });
+  await john.call(multisig, "approve", { proposal_id: thresholdId });
+  await alice.call(
+    multisig,
+    "execute",
+    { proposal_id: thresholdId },
+    { gas: "100000000000000" }
+  );
+
+  t.is(await multisig.view("get_threshold"), 1);
+
+  // The pending proposal reached the lowered threshold, it still has to wait out the delay
+  const started: any = await multisig.view("get_proposal", { proposal_id: pendingId });
+  t.true(started.eta > 0);
+
+  const error = await t.throwsAsync(
+    alice.call(multisig, "execute", { proposal_id: pendingId }, { gas: "100000000000000" })
+  );
+  // t.log(error?.message);
+  t.not(error, undefined); // Timelock not ready
+  t.is(await multisig.view("get_threshold"), 1);
 });
 
-// Executable Near Contract tests
 test("Gateway - call executable contract", async (t) => {
   const { worker, root, contract, executableContract } = t.context.accounts;
 
@@ -1,20 +1,34 @@
 const { ethers } = require("hardhat");
 import { SignerWithAddress } from "@nomiclabs/hardhat-ethers/signers";
 import { sortBy } from "lodash";
+import { KeyPair } from "near-workspaces";
+
+// An operator is either an EVM signer or an ed25519 key pair
+export type Operator = SignerWithAddress | KeyPair;
 
 class Utils {
   static getAddresses = (signers: SignerWithAddress[]) =>
     signers.map(({ address }) => address);
 
+  // Parses the `axelar_near` NEP-297 events logged by a transaction
+  static getAxelarEvents = (tx: any): any[] =>
+    tx.result.receipts_outcome
+      .flatMap((receipt: any) => receipt.outcome.logs)
+      .filter((log: string) => log.includes("axelar_near"))
+      .map((log: string) => JSON.parse(log.slice("EVENT_JSON:".length)));
+
   static getRandomID = () => {
     return ethers.utils.id(Math.floor(Math.random() * 1e10).toString());
   };
 
+  static toBase64 = (data: string | Uint8Array) =>
+    Buffer.from(ethers.utils.arrayify(data)).toString("base64");
+
   static getWeightedSignaturesProof = async (
     data: string,
     operators: SignerWithAddress[],
-    weights: number[],
-    threshold: number,
+    weights: (number | string)[],
+    threshold: number | string,
     signers: SignerWithAddress[]
   ) => {
     const hash = ethers.utils.arrayify(ethers.utils.keccak256(data));
@@ -31,8 +45,8 @@ class Utils {
 
   static getTransferWeightedOperatorshipCommand = async (
     newOperators: string[],
-    newWeights: number[],
-    threshold: number
+    newWeights: (number | string)[],
+    threshold: number | string
   ) => {
     return ethers.utils.defaultAbiCoder.encode(
       ["address[]", "uint256[]", "uint256"],
@@ -79,11 +93,310 @@ class Utils {
     );
   };
 
+  static getGovernanceProposalPayload = (
+    command: number,
+    target: string,
+    method: string,
+    args: object,
+    deposit: string,
+    eta: number
+  ) =>
+    ethers.utils.defaultAbiCoder.encode(
+      ["uint256", "string", "string", "bytes", "uint256", "uint256"],
+      [
+        command,
+        target,
+        method,
+        ethers.utils.toUtf8Bytes(JSON.stringify(args)),
+        deposit,
+        eta,
+      ]
+    );
+
+  static getApproveMessagesData = (
+    messages: {
+      source_chain: string;
+      message_id: string;
+      source_address: string;
+      contract_address: string;
+      payload_hash: string;
+    }[]
+  ) =>
+    ethers.utils.defaultAbiCoder.encode(
+      ["string", "tuple(string,string,string,string,bytes32)[]"],
+      [
+        "approveMessages",
+        messages.map((message) => [
+          message.source_chain,
+          message.message_id,
+          message.source_address,
+          message.contract_address,
+          message.payload_hash,
+        ]),
+      ]
+    );
+
+  static getRotateSignersData = (
+    newOperators: string[],
+    newWeights: (number | string)[],
+    threshold: number | string,
+    nonce: string = ethers.constants.HashZero
+  ) =>
+    ethers.utils.defaultAbiCoder.encode(
+      ["string", "address[]", "uint256[]", "uint256", "bytes32"],
+      ["rotateSigners", newOperators, newWeights, threshold, nonce]
+    );
+
+  static getWeightedSignersHash = (
+    operators: string[],
+    weights: (number | string)[],
+    threshold: number | string,
+    nonce: string
+  ) =>
+    ethers.utils.keccak256(
+      ethers.utils.defaultAbiCoder.encode(
+        ["address[]", "uint256[]", "uint256", "bytes32"],
+        [operators, weights, threshold, nonce]
+      )
+    );
+
+  static BORSH_ENCODING_VERSION = 1;
+  static BORSH_BATCH_DOMAIN_TAG = "axelar-near-borsh-batch-v1";
+
+  static borshU32 = (value: number) => {
+    const buffer = Buffer.alloc(4);
+    buffer.writeUInt32LE(value);
+    return buffer;
+  };
+
+  static borshU64 = (value: number) => {
+    const buffer = Buffer.alloc(8);
+    buffer.writeBigUInt64LE(BigInt(value));
+    return buffer;
+  };
+
+  static borshU256 = (value: number | string) =>
+    Buffer.from(
+      ethers.utils.zeroPad(ethers.BigNumber.from(value).toHexString(), 32)
+    ).reverse();
+
+  static borshBytes = (data: string | Uint8Array) => {
+    const bytes = Buffer.from(ethers.utils.arrayify(data));
+    return Buffer.concat([Utils.borshU32(bytes.length), bytes]);
+  };
+
+  static borshString = (value: string) =>
+    Utils.borshBytes(ethers.utils.toUtf8Bytes(value));
+
+  static borshVec = <T>(items: T[], encode: (item: T) => Buffer) =>
+    Buffer.concat([Utils.borshU32(items.length), ...items.map(encode)]);
+
+  static buildBorshCommandBatch = (
+    chainId: number,
+    commandIDs: string[],
+    commandNames: string[],
+    commands: string[]
+  ) =>
+    Buffer.concat([
+      Utils.borshU64(chainId),
+      Utils.borshVec(commandIDs, (id) => Buffer.from(ethers.utils.arrayify(id))),
+      Utils.borshVec(commandNames, Utils.borshString),
+      Utils.borshVec(commands, Utils.borshBytes),
+    ]);
+
+  // The params of commands in a Borsh batch are Borsh-encoded, without a version byte
+  static getBorshApproveContractCall = (
+    sourceChain: string,
+    source: string,
+    destination: string,
+    payloadHash: string,
+    sourceTxHash: string,
+    sourceEventIndex: number
+  ) =>
+    Buffer.concat([
+      Utils.borshString(sourceChain),
+      Utils.borshString(source),
+      Utils.borshString(destination),
+      Buffer.from(ethers.utils.arrayify(payloadHash)),
+      Buffer.from(ethers.utils.arrayify(sourceTxHash)),
+      Utils.borshU64(sourceEventIndex),
+    ]);
+
+  static getBorshWeightedSignaturesProof = async (
+    hash: string,
+    operators: SignerWithAddress[],
+    weights: (number | string)[],
+    threshold: number | string,
+    signers: SignerWithAddress[],
+    nonce: string = ethers.constants.HashZero
+  ) => {
+    const signatures = await Promise.all(
+      sortBy(signers, (wallet) => wallet.address.toLowerCase()).map((wallet) =>
+        wallet.signMessage(ethers.utils.arrayify(hash))
+      )
+    );
+    return Buffer.concat([
+      Buffer.from([Utils.BORSH_ENCODING_VERSION]),
+      Utils.borshVec(operators, Utils.borshOperatorKey),
+      Utils.borshVec(weights, Utils.borshU256),
+      Utils.borshU256(threshold),
+      Buffer.from(ethers.utils.arrayify(nonce)),
+      Utils.borshVec(signatures, Utils.borshBytes),
+    ]);
+  };
+
+  static isEd25519 = (operator: Operator): operator is KeyPair =>
+    !("address" in operator);
+
+  static getOperatorKeyBytes = (operator: Operator) =>
+    Utils.isEd25519(operator)
+      ? Buffer.from(operator.getPublicKey().data)
+      : Buffer.from(ethers.utils.arrayify(operator.address));
+
+  // Tag byte 0 for secp256k1 addresses, 1 for ed25519 public keys
+  static borshOperatorKey = (operator: Operator) =>
+    Buffer.concat([
+      Buffer.from([Utils.isEd25519(operator) ? 1 : 0]),
+      Utils.getOperatorKeyBytes(operator),
+    ]);
+
+  // secp256k1 operators sort before ed25519 operators, each by their key bytes
+  static sortOperators = (operators: Operator[]) =>
+    sortBy(operators, (operator) =>
+      ethers.utils.hexlify(Utils.borshOperatorKey(operator))
+    );
+
+  static getOperatorKeyString = (operator: Operator) =>
+    Utils.isEd25519(operator)
+      ? operator.getPublicKey().toString()
+      : operator.address.toLowerCase();
+
+  static getBorshWeightedSigners = (
+    operators: Operator[],
+    weights: (number | string)[],
+    threshold: number | string,
+    nonce: string = ethers.constants.HashZero
+  ) =>
+    Buffer.concat([
+      Buffer.from([Utils.BORSH_ENCODING_VERSION]),
+      Utils.borshVec(operators, Utils.borshOperatorKey),
+      Utils.borshVec(weights, Utils.borshU256),
+      Utils.borshU256(threshold),
+      Buffer.from(ethers.utils.arrayify(nonce)),
+    ]);
+
+  // Operator sets with ed25519 keys hash their operators as `bytes[]` of tagged keys
+  static getMixedWeightedSignersHash = (
+    operators: Operator[],
+    weights: (number | string)[],
+    threshold: number | string
+  ) =>
+    ethers.utils.keccak256(
+      ethers.utils.defaultAbiCoder.encode(
+        ["bytes[]", "uint256[]", "uint256"],
+        [operators.map(Utils.borshOperatorKey), weights, threshold]
+      )
+    );
+
+  // Both schemes sign the Ethereum signed message hash of `hash`
+  static getMixedBorshSignaturesProof = async (
+    hash: string,
+    operators: Operator[],
+    weights: (number | string)[],
+    threshold: number | string,
+    signers: Operator[]
+  ) => {
+    const message = ethers.utils.arrayify(
+      ethers.utils.hashMessage(ethers.utils.arrayify(hash))
+    );
+    const signatures = await Promise.all(
+      operators
+        .filter((operator) => signers.includes(operator))
+        .map(async (operator) =>
+          Utils.isEd25519(operator)
+            ? Buffer.from(operator.sign(message).signature)
+            : ethers.utils.arrayify(
+                await operator.signMessage(ethers.utils.arrayify(hash))
+              )
+        )
+    );
+    return ethers.utils.hexlify(
+      Buffer.concat([
+        Utils.getBorshWeightedSigners(operators, weights, threshold),
+        Utils.borshVec(signatures, Utils.borshBytes),
+      ])
+    );
+  };
+
+  // ed25519 operators can sign any 32-byte message, e.g. one in the NEAR signing domain
+  static getEd25519BorshSignaturesProof = (
+    message: string,
+    operators: KeyPair[],
+    weights: (number | string)[],
+    threshold: number | string,
+    signers: KeyPair[]
+  ) =>
+    ethers.utils.hexlify(
+      Buffer.concat([
+        Utils.getBorshWeightedSigners(operators, weights, threshold),
+        Utils.borshVec(
+          operators
+            .filter((operator) => signers.includes(operator))
+            .map((operator) =>
+              Buffer.from(
+                operator.sign(ethers.utils.arrayify(message)).signature
+              )
+            ),
+          (signature) => Utils.borshBytes(signature)
+        ),
+      ])
+    );
+
+  static NEAR_SIGNING_DOMAIN_TAG = "axelar-near-gateway-v1";
+
+  static getDomainSeparator = (chainName: string, accountId: string) =>
+    ethers.utils.keccak256(
+      Buffer.concat([
+        Utils.borshString(Utils.NEAR_SIGNING_DOMAIN_TAG),
+        Utils.borshString(chainName),
+        Utils.borshString(accountId),
+      ])
+    );
+
+  static getNearSignedMessageHash = (domainSeparator: string, hash: string) =>
+    ethers.utils.keccak256(ethers.utils.concat([domainSeparator, hash]));
+
+  static getSignedBorshExecuteInput = async (
+    data: Buffer,
+    operators: SignerWithAddress[],
+    weights: (number | string)[],
+    threshold: number | string,
+    signers: SignerWithAddress[]
+  ) => {
+    const hash = ethers.utils.keccak256(
+      Buffer.concat([Buffer.from(Utils.BORSH_BATCH_DOMAIN_TAG), data])
+    );
+    const proof = await Utils.getBorshWeightedSignaturesProof(
+      hash,
+      operators,
+      weights,
+      threshold,
+      signers
+    );
+    return ethers.utils.hexlify(
+      Buffer.concat([
+        Buffer.from([Utils.BORSH_ENCODING_VERSION]),
+        Utils.borshBytes(data),
+        Utils.borshBytes(proof),
+      ])
+    );
+  };
+
   static getSignedWeightedExecuteInput = async (
     data: string,
     operators: SignerWithAddress[],
-    weights: number[],
-    threshold: number,
+    weights: (number | string)[],
+    threshold: number | string,
     signers: SignerWithAddress[]
   ) => {
     return ethers.utils.defaultAbiCoder.encode(
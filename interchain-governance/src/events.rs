@@ -0,0 +1,89 @@
+use near_contract_tools::event;
+
+/// `ProposalScheduledEvent` is emitted when the governance chain schedules a proposal.
+///
+/// Properties:
+///
+/// * `proposal_hash`: The hash of the proposal, see `InterchainGovernance::proposal_hash`.
+/// * `target`: The account the proposal calls.
+/// * `method`: The method the proposal calls.
+/// * `args`: The arguments of the call.
+/// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+/// * `eta`: The timestamp the proposal can be executed from, in seconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct ProposalScheduledEvent {
+    pub proposal_hash: String,
+    pub target: String,
+    pub method: String,
+    pub args: String,
+    pub deposit: String,
+    pub eta: u64,
+}
+
+/// `ProposalCancelledEvent` is emitted when the governance chain cancels a scheduled proposal.
+///
+/// Properties:
+///
+/// * `proposal_hash`: The hash of the proposal.
+/// * `target`: The account the proposal calls.
+/// * `method`: The method the proposal calls.
+/// * `args`: The arguments of the call.
+/// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+/// * `eta`: The timestamp the proposal could have been executed from, in seconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct ProposalCancelledEvent {
+    pub proposal_hash: String,
+    pub target: String,
+    pub method: String,
+    pub args: String,
+    pub deposit: String,
+    pub eta: u64,
+}
+
+/// `ProposalExecutedEvent` is emitted when a proposal is executed after its timelock.
+///
+/// Properties:
+///
+/// * `proposal_hash`: The hash of the proposal.
+/// * `target`: The account the proposal calls.
+/// * `method`: The method the proposal calls.
+/// * `args`: The arguments of the call.
+/// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+/// * `timestamp`: The block timestamp of the execution, in seconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct ProposalExecutedEvent {
+    pub proposal_hash: String,
+    pub target: String,
+    pub method: String,
+    pub args: String,
+    pub deposit: String,
+    pub timestamp: u64,
+}
+
+/// `GovernanceUpdatedEvent` is emitted when a proposal changes the governance chain and address.
+///
+/// Properties:
+///
+/// * `previous_governance_chain`: The chain proposals were accepted from.
+/// * `previous_governance_address`: The address proposals were accepted from.
+/// * `new_governance_chain`: The chain proposals are accepted from now.
+/// * `new_governance_address`: The address proposals are accepted from now.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct GovernanceUpdatedEvent {
+    pub previous_governance_chain: String,
+    pub previous_governance_address: String,
+    pub new_governance_chain: String,
+    pub new_governance_address: String,
+}
+
+/// `MinimumTimeDelayUpdatedEvent` is emitted when a proposal changes the minimum time delay.
+///
+/// Properties:
+///
+/// * `previous_delay`: The previous minimum delay, in seconds.
+/// * `new_delay`: The new minimum delay, in seconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct MinimumTimeDelayUpdatedEvent {
+    pub previous_delay: u64,
+    pub new_delay: u64,
+}
@@ -0,0 +1,402 @@
+/*
+ * Axelar Interchain Governance for NEAR.
+ *
+ * Proposals are sent over GMP from the governance chain, and can only be executed once their
+ * timelock has passed. Set the owner of the gateway to this contract to govern it. The governance
+ * settings of this contract can only be changed by proposals that call it.
+ *
+ */
+
+mod events;
+
+use axelar_executable_near::ethabi::{ParamType, Token};
+use axelar_executable_near::utils::{abi_decode, keccak256, to_eth_hex_string};
+use axelar_executable_near::{impl_axelar_executable, AxelarExecutable, ContractExecutable};
+use events::{
+    GovernanceUpdatedEvent, MinimumTimeDelayUpdatedEvent, ProposalCancelledEvent,
+    ProposalExecutedEvent, ProposalScheduledEvent,
+};
+use near_contract_tools::standard::nep297::Event;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::PanicOnDefault;
+use near_sdk::{env, near_bindgen, AccountId, Gas, Promise, PromiseResult};
+
+/// The command of a payload that schedules a proposal.
+pub const SCHEDULE_TIME_LOCK_PROPOSAL: u64 = 0;
+/// The command of a payload that cancels a scheduled proposal.
+pub const CANCEL_TIME_LOCK_PROPOSAL: u64 = 1;
+
+/// The gas kept back by `execute_proposal`, the rest of the prepaid gas goes to the proposal call.
+const EXECUTE_PROPOSAL_GAS_RESERVE: Gas = Gas(10_000_000_000_000);
+/// The least gas a proposal call is made with.
+const MINIMUM_PROPOSAL_GAS: Gas = Gas(30_000_000_000_000);
+/// The gas attached to the `on_proposal_executed` callback, out of the reserve.
+const ON_PROPOSAL_EXECUTED_GAS: Gas = Gas(5_000_000_000_000);
+
+/// `InterchainGovernance` executes proposals of the governance chain after a timelock.
+///
+/// Properties:
+///
+/// * `gateway_account_id`: The account id of the Axelar gateway.
+/// * `governance_chain`: The only chain proposals are accepted from.
+/// * `governance_address`: The only address on the governance chain proposals are accepted from.
+/// * `minimum_time_delay`: The minimum time between scheduling and executing a proposal, in
+///   seconds.
+/// * `proposal_timelocks`: The timestamp each scheduled proposal can be executed from, in seconds,
+///   by proposal hash.
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct InterchainGovernance {
+    pub gateway_account_id: AccountId,
+    pub governance_chain: String,
+    pub governance_address: String,
+    pub minimum_time_delay: u64,
+    proposal_timelocks: LookupMap<[u8; 32], u64>,
+}
+
+#[near_bindgen]
+impl InterchainGovernance {
+    /// `new` initializes the governance contract
+    ///
+    /// Arguments:
+    ///
+    /// * `gateway_account_id`: The account id of the Axelar gateway.
+    /// * `governance_chain`: The only chain proposals are accepted from.
+    /// * `governance_address`: The only address on the governance chain proposals are accepted from.
+    /// * `minimum_time_delay`: The minimum time between scheduling and executing a proposal, in
+    ///   seconds.
+    ///
+    /// Returns:
+    ///
+    /// The contract is being returned.
+    #[init]
+    pub fn new(
+        gateway_account_id: AccountId,
+        governance_chain: String,
+        governance_address: String,
+        minimum_time_delay: u64,
+    ) -> Self {
+        if governance_chain.is_empty() || governance_address.is_empty() {
+            env::panic_str("Invalid governance");
+        }
+
+        Self {
+            gateway_account_id,
+            governance_chain,
+            governance_address,
+            minimum_time_delay,
+            proposal_timelocks: LookupMap::new(b"proposal_timelocks".to_vec()),
+        }
+    }
+
+    // View functions
+
+    pub fn get_gateway_account_id(&self) -> AccountId {
+        self.gateway_account_id.clone()
+    }
+
+    pub fn get_governance_chain(&self) -> String {
+        self.governance_chain.clone()
+    }
+
+    pub fn get_governance_address(&self) -> String {
+        self.governance_address.clone()
+    }
+
+    pub fn get_minimum_time_delay(&self) -> u64 {
+        self.minimum_time_delay
+    }
+
+    /// `get_proposal_eta` returns when a proposal can be executed
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The account the proposal calls.
+    /// * `method`: The method the proposal calls.
+    /// * `args`: The arguments of the call.
+    /// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+    ///
+    /// Returns:
+    ///
+    /// The timestamp the proposal can be executed from, in seconds, or `0` if it is not scheduled.
+    pub fn get_proposal_eta(
+        &self,
+        target: AccountId,
+        method: String,
+        args: Bytes,
+        deposit: U128,
+    ) -> u64 {
+        let proposal_hash = Self::proposal_hash(target.as_str(), &method, &args.0, deposit.0);
+
+        self.proposal_timelocks.get(&proposal_hash).unwrap_or(0)
+    }
+
+    // Payable functions
+
+    /// `execute_proposal` calls the target of a scheduled proposal once its timelock has passed.
+    /// Anyone can call it, the deposit of the call is paid from the balance of this contract.
+    ///
+    /// The call gets the prepaid gas minus a reserve, and at least `MINIMUM_PROPOSAL_GAS`. If it
+    /// fails, the proposal is scheduled again with the same timelock, see `on_proposal_executed`.
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The account the proposal calls.
+    /// * `method`: The method the proposal calls.
+    /// * `args`: The arguments of the call.
+    /// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+    ///
+    /// Returns:
+    ///
+    /// The promise of whether the proposal call succeeded.
+    #[payable]
+    pub fn execute_proposal(
+        &mut self,
+        target: AccountId,
+        method: String,
+        args: Bytes,
+        deposit: U128,
+    ) -> Promise {
+        let proposal_hash = Self::proposal_hash(target.as_str(), &method, &args.0, deposit.0);
+
+        let eta = self.proposal_timelocks.get(&proposal_hash).unwrap_or(0);
+
+        if eta == 0 {
+            env::panic_str("Proposal not scheduled");
+        }
+
+        let timestamp = Self::block_timestamp_seconds();
+
+        if timestamp < eta {
+            env::panic_str("Timelock not ready");
+        }
+
+        let gas = Gas(env::prepaid_gas()
+            .0
+            .saturating_sub(env::used_gas().0 + EXECUTE_PROPOSAL_GAS_RESERVE.0));
+
+        if gas < MINIMUM_PROPOSAL_GAS {
+            env::panic_str("Insufficient gas");
+        }
+
+        // Removed before the call, so the proposal cannot be executed twice in the meantime
+        self.proposal_timelocks.remove(&proposal_hash);
+
+        Promise::new(target.clone())
+            .function_call(method.clone(), args.0.clone(), deposit.0, gas)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(ON_PROPOSAL_EXECUTED_GAS)
+                    .on_proposal_executed(target, method, args, deposit, eta),
+            )
+    }
+
+    // Callbacks
+
+    /// It emits a `ProposalExecutedEvent` if the proposal call succeeded, and schedules the
+    /// proposal again with its timelock if it failed
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The account the proposal called.
+    /// * `method`: The method the proposal called.
+    /// * `args`: The arguments of the call.
+    /// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+    /// * `eta`: The timestamp the proposal could be executed from, in seconds.
+    ///
+    /// Returns:
+    ///
+    /// Whether the proposal call succeeded.
+    #[private]
+    pub fn on_proposal_executed(
+        &mut self,
+        target: AccountId,
+        method: String,
+        args: Bytes,
+        deposit: U128,
+        eta: u64,
+    ) -> bool {
+        let proposal_hash = Self::proposal_hash(target.as_str(), &method, &args.0, deposit.0);
+
+        if !matches!(env::promise_result(0), PromiseResult::Successful(_)) {
+            // A proposal rescheduled in the meantime keeps its new timelock
+            if !self.proposal_timelocks.contains_key(&proposal_hash) {
+                self.proposal_timelocks.insert(&proposal_hash, &eta);
+            }
+
+            return false;
+        }
+
+        ProposalExecutedEvent {
+            proposal_hash: to_eth_hex_string(proposal_hash),
+            target: target.to_string(),
+            method,
+            args: args.to_string(),
+            deposit: deposit.0.to_string(),
+            timestamp: Self::block_timestamp_seconds(),
+        }
+        .emit();
+
+        true
+    }
+
+    // Governance functions
+
+    /// `set_governance` changes the chain and address proposals are accepted from. It can only be
+    /// called by a proposal of the current governance.
+    ///
+    /// Arguments:
+    ///
+    /// * `governance_chain`: The only chain proposals are accepted from.
+    /// * `governance_address`: The only address on the governance chain proposals are accepted from.
+    #[private]
+    pub fn set_governance(&mut self, governance_chain: String, governance_address: String) {
+        if governance_chain.is_empty() || governance_address.is_empty() {
+            env::panic_str("Invalid governance");
+        }
+
+        let event = GovernanceUpdatedEvent {
+            previous_governance_chain: self.governance_chain.clone(),
+            previous_governance_address: self.governance_address.clone(),
+            new_governance_chain: governance_chain.clone(),
+            new_governance_address: governance_address.clone(),
+        };
+
+        self.governance_chain = governance_chain;
+        self.governance_address = governance_address;
+
+        event.emit();
+    }
+
+    /// `set_minimum_time_delay` changes the minimum time between scheduling and executing a
+    /// proposal. It can only be called by a proposal of the governance, and only applies to
+    /// proposals scheduled afterwards.
+    ///
+    /// Arguments:
+    ///
+    /// * `minimum_time_delay`: The new minimum delay, in seconds.
+    #[private]
+    pub fn set_minimum_time_delay(&mut self, minimum_time_delay: u64) {
+        let event = MinimumTimeDelayUpdatedEvent {
+            previous_delay: self.minimum_time_delay,
+            new_delay: minimum_time_delay,
+        };
+
+        self.minimum_time_delay = minimum_time_delay;
+
+        event.emit();
+    }
+
+    // Internal functions
+
+    /// It returns the hash a proposal is scheduled under,
+    /// `keccak256(abi.encode(string target, string method, bytes args, uint256 deposit))`
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The account the proposal calls.
+    /// * `method`: The method the proposal calls.
+    /// * `args`: The arguments of the call.
+    /// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+    ///
+    /// Returns:
+    ///
+    /// A 32 byte array
+    fn proposal_hash(target: &str, method: &str, args: &[u8], deposit: u128) -> [u8; 32] {
+        keccak256(axelar_executable_near::ethabi::encode(&[
+            Token::String(target.to_string()),
+            Token::String(method.to_string()),
+            Token::Bytes(args.to_vec()),
+            Token::Uint(deposit.into()),
+        ]))
+    }
+
+    fn block_timestamp_seconds() -> u64 {
+        env::block_timestamp() / 1_000_000_000
+    }
+}
+
+impl ContractExecutable for InterchainGovernance {
+    /// It schedules or cancels a proposal sent by the governance address of the governance chain
+    ///
+    /// The payload is the ABI encoding of `(uint256 command, string target, string method,
+    /// bytes args, uint256 deposit, uint256 eta)`. Proposals are scheduled for `eta`, or for the
+    /// minimum time delay from now if `eta` is earlier.
+    fn _execute(&mut self, source_chain: String, source_address: String, payload: Vec<u8>) {
+        if source_chain != self.governance_chain || source_address != self.governance_address {
+            env::panic_str("Not governance");
+        }
+
+        let tokens = abi_decode(
+            &payload,
+            &[
+                ParamType::Uint(256),
+                ParamType::String,
+                ParamType::String,
+                ParamType::Bytes,
+                ParamType::Uint(256),
+                ParamType::Uint(256),
+            ],
+        )
+        .unwrap_or_else(|e| env::panic_str(&e));
+
+        let command = tokens[0].clone().into_uint().unwrap();
+        let target = tokens[1].clone().into_string().unwrap();
+        let method = tokens[2].clone().into_string().unwrap();
+        let args = tokens[3].clone().into_bytes().unwrap();
+        let deposit = tokens[4].clone().into_uint().unwrap();
+        let eta = tokens[5].clone().into_uint().unwrap();
+
+        if target.parse::<AccountId>().is_err() {
+            env::panic_str("Invalid target");
+        }
+
+        if deposit.bits() > 128 {
+            env::panic_str("Invalid deposit");
+        }
+
+        let proposal_hash = Self::proposal_hash(&target, &method, &args, deposit.as_u128());
+
+        if command == SCHEDULE_TIME_LOCK_PROPOSAL.into() {
+            let minimum_eta = Self::block_timestamp_seconds() + self.minimum_time_delay;
+            let eta = if eta.bits() > 64 {
+                u64::MAX
+            } else {
+                eta.as_u64().max(minimum_eta)
+            };
+
+            self.proposal_timelocks.insert(&proposal_hash, &eta);
+
+            ProposalScheduledEvent {
+                proposal_hash: to_eth_hex_string(proposal_hash),
+                target,
+                method,
+                args: Bytes(args).to_string(),
+                deposit: deposit.to_string(),
+                eta,
+            }
+            .emit();
+        } else if command == CANCEL_TIME_LOCK_PROPOSAL.into() {
+            let eta = self
+                .proposal_timelocks
+                .remove(&proposal_hash)
+                .unwrap_or_else(|| env::panic_str("Proposal not scheduled"));
+
+            ProposalCancelledEvent {
+                proposal_hash: to_eth_hex_string(proposal_hash),
+                target,
+                method,
+                args: Bytes(args).to_string(),
+                deposit: deposit.to_string(),
+                eta,
+            }
+            .emit();
+        } else {
+            env::panic_str("Invalid command");
+        }
+    }
+}
+
+impl_axelar_executable!(InterchainGovernance, gateway_account_id, _execute);
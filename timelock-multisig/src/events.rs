@@ -0,0 +1,71 @@
+use near_contract_tools::event;
+
+/// `ProposalCreatedEvent` is emitted when a signer proposes a call.
+///
+/// Properties:
+///
+/// * `proposal_id`: The id of the proposal.
+/// * `proposer`: The signer that proposed the call, it approves it as well.
+/// * `target`: The account the proposal calls.
+/// * `method`: The method the proposal calls.
+/// * `args`: The arguments of the call.
+/// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub proposer: String,
+    pub target: String,
+    pub method: String,
+    pub args: String,
+    pub deposit: String,
+}
+
+/// `ProposalApprovalsUpdatedEvent` is emitted when a signer approves a proposal or revokes its
+/// approval.
+///
+/// Properties:
+///
+/// * `proposal_id`: The id of the proposal.
+/// * `signer`: The signer that approved or revoked.
+/// * `approved`: Whether the signer approved the proposal.
+/// * `approvals`: The number of approvals of the proposal.
+/// * `eta`: The timestamp the proposal can be executed from, in seconds, or `0` below the
+///   threshold.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct ProposalApprovalsUpdatedEvent {
+    pub proposal_id: u64,
+    pub signer: String,
+    pub approved: bool,
+    pub approvals: u64,
+    pub eta: u64,
+}
+
+/// `ProposalExecutedEvent` is emitted when a proposal is executed.
+///
+/// Properties:
+///
+/// * `proposal_id`: The id of the proposal.
+/// * `executor`: The signer that executed the proposal.
+/// * `emergency`: Whether the proposal skipped the delay with the emergency threshold.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+    pub executor: String,
+    pub emergency: bool,
+}
+
+/// `SignersUpdatedEvent` is emitted when the multisig changes its signers.
+///
+/// Properties:
+///
+/// * `signers`: The new signers.
+/// * `threshold`: The new number of approvals needed to execute after the delay.
+/// * `emergency_threshold`: The new number of approvals needed to execute without the delay.
+/// * `delay`: The new delay, in seconds.
+#[event(standard = "axelar_near", version = "1.0.0")]
+pub struct SignersUpdatedEvent {
+    pub signers: Vec<String>,
+    pub threshold: u64,
+    pub emergency_threshold: u64,
+    pub delay: u64,
+}
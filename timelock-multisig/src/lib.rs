@@ -0,0 +1,446 @@
+/*
+ * Axelar Timelock Multisig for NEAR.
+ *
+ * An M-of-N multisig of NEAR accounts that proposes, approves and executes calls, after a delay
+ * or right away with the emergency threshold. Set the owner of the gateway to this contract to
+ * share the owner role between the signers.
+ *
+ */
+
+mod events;
+
+use axelar_executable_near::Bytes;
+use events::{
+    ProposalApprovalsUpdatedEvent, ProposalCreatedEvent, ProposalExecutedEvent, SignersUpdatedEvent,
+};
+use near_contract_tools::standard::nep297::Event;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedMap;
+use near_sdk::json_types::{Base64VecU8, U128};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::PanicOnDefault;
+use near_sdk::{env, near_bindgen, AccountId, Gas, Promise, PromiseResult};
+
+/// The gas kept back by `execute`, the rest of the prepaid gas goes to the proposal call.
+const EXECUTE_GAS_RESERVE: Gas = Gas(10_000_000_000_000);
+/// The least gas a proposal call is made with.
+const MINIMUM_PROPOSAL_GAS: Gas = Gas(30_000_000_000_000);
+/// The gas attached to the `on_execute` callback, out of the reserve.
+const ON_EXECUTE_GAS: Gas = Gas(5_000_000_000_000);
+
+/// `Proposal` is a call the signers approve before it is executed.
+///
+/// Properties:
+///
+/// * `target`: The account the proposal calls.
+/// * `method`: The method the proposal calls.
+/// * `args`: The arguments of the call.
+/// * `deposit`: The deposit attached to the call, in yoctoNEAR.
+/// * `approvals`: The signers that approved the proposal.
+/// * `eta`: The timestamp the proposal can be executed from, in seconds, set once it reaches the
+///   threshold, `0` while it is below.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub target: AccountId,
+    pub method: String,
+    pub args: Base64VecU8,
+    pub deposit: U128,
+    pub approvals: Vec<AccountId>,
+    pub eta: u64,
+}
+
+/// `TimelockMultisig` executes the calls its signers approved.
+///
+/// Properties:
+///
+/// * `signers`: The accounts that propose, approve and execute calls.
+/// * `threshold`: The number of approvals needed to execute a call after the delay.
+/// * `emergency_threshold`: The number of approvals needed to execute a call right away.
+/// * `delay`: The time between a call reaching the threshold and its execution, in seconds.
+/// * `proposal_count`: The number of proposals created, the id of the next one.
+/// * `proposals`: The proposals that were not executed yet, by id.
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct TimelockMultisig {
+    pub signers: Vec<AccountId>,
+    pub threshold: u64,
+    pub emergency_threshold: u64,
+    pub delay: u64,
+    pub proposal_count: u64,
+    proposals: UnorderedMap<u64, Proposal>,
+}
+
+#[near_bindgen]
+impl TimelockMultisig {
+    /// `new` initializes the multisig
+    ///
+    /// Arguments:
+    ///
+    /// * `signers`: The accounts that propose, approve and execute calls.
+    /// * `threshold`: The number of approvals needed to execute a call after the delay.
+    /// * `emergency_threshold`: The number of approvals needed to execute a call right away.
+    /// * `delay`: The time between a call reaching the threshold and its execution, in seconds.
+    ///
+    /// Returns:
+    ///
+    /// The contract is being returned.
+    #[init]
+    pub fn new(
+        signers: Vec<AccountId>,
+        threshold: u64,
+        emergency_threshold: u64,
+        delay: u64,
+    ) -> Self {
+        let mut contract = Self {
+            signers: vec![],
+            threshold: 0,
+            emergency_threshold: 0,
+            delay: 0,
+            proposal_count: 0,
+            proposals: UnorderedMap::new(b"proposals".to_vec()),
+        };
+
+        contract.internal_set_signers(signers, threshold, emergency_threshold, delay);
+
+        contract
+    }
+
+    // View functions
+
+    pub fn get_signers(&self) -> Vec<AccountId> {
+        self.signers.clone()
+    }
+
+    pub fn get_threshold(&self) -> u64 {
+        self.threshold
+    }
+
+    pub fn get_emergency_threshold(&self) -> u64 {
+        self.emergency_threshold
+    }
+
+    pub fn get_delay(&self) -> u64 {
+        self.delay
+    }
+
+    pub fn get_proposal(&self, proposal_id: u64) -> Option<Proposal> {
+        self.proposals.get(&proposal_id)
+    }
+
+    // Only signers
+
+    /// `propose` creates a proposal to call a method, approved by the proposer
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The account to call, the gateway for owner-only gateway calls.
+    /// * `method`: The method to call.
+    /// * `args`: The JSON arguments of the call, as `0x`-prefixed hex or Base64.
+    /// * `deposit`: The deposit to attach to the call, paid from the balance of this contract.
+    ///
+    /// Returns:
+    ///
+    /// The id of the proposal.
+    pub fn propose(
+        &mut self,
+        target: AccountId,
+        method: String,
+        args: Bytes,
+        deposit: Option<U128>,
+    ) -> u64 {
+        let proposer = self.require_signer();
+
+        let proposal_id = self.proposal_count;
+        self.proposal_count += 1;
+
+        let proposal = Proposal {
+            target,
+            method,
+            args: args.0.into(),
+            deposit: deposit.unwrap_or(U128(0)),
+            approvals: vec![],
+            eta: 0,
+        };
+
+        ProposalCreatedEvent {
+            proposal_id,
+            proposer: proposer.to_string(),
+            target: proposal.target.to_string(),
+            method: proposal.method.clone(),
+            args: Bytes(proposal.args.0.clone()).to_string(),
+            deposit: proposal.deposit.0.to_string(),
+        }
+        .emit();
+
+        self.proposals.insert(&proposal_id, &proposal);
+        self.approve(proposal_id);
+
+        proposal_id
+    }
+
+    /// `approve` adds the approval of the caller to a proposal. The delay starts once the proposal
+    /// reaches the threshold.
+    ///
+    /// Arguments:
+    ///
+    /// * `proposal_id`: The id of the proposal.
+    pub fn approve(&mut self, proposal_id: u64) {
+        let signer = self.require_signer();
+        let mut proposal = self.internal_get_proposal(proposal_id);
+
+        if proposal.approvals.contains(&signer) {
+            env::panic_str("Already approved");
+        }
+
+        proposal.approvals.push(signer.clone());
+
+        self.internal_update_approvals(proposal_id, proposal, signer, true);
+    }
+
+    /// `revoke` removes the approval of the caller from a proposal. The delay starts over if the
+    /// proposal falls below the threshold.
+    ///
+    /// Arguments:
+    ///
+    /// * `proposal_id`: The id of the proposal.
+    pub fn revoke(&mut self, proposal_id: u64) {
+        let signer = self.require_signer();
+        let mut proposal = self.internal_get_proposal(proposal_id);
+
+        if !proposal.approvals.contains(&signer) {
+            env::panic_str("Not approved");
+        }
+
+        proposal.approvals.retain(|x| *x != signer);
+
+        self.internal_update_approvals(proposal_id, proposal, signer, false);
+    }
+
+    /// `execute` calls the target of a proposal once its delay has passed, or right away if it
+    /// reached the emergency threshold
+    ///
+    /// The call gets the prepaid gas minus a reserve, and at least `MINIMUM_PROPOSAL_GAS`. If it
+    /// fails, the proposal is restored with its approvals, see `on_execute`.
+    ///
+    /// Arguments:
+    ///
+    /// * `proposal_id`: The id of the proposal.
+    ///
+    /// Returns:
+    ///
+    /// The promise of whether the proposal call succeeded.
+    pub fn execute(&mut self, proposal_id: u64) -> Promise {
+        let executor = self.require_signer();
+        let proposal = self.internal_get_proposal(proposal_id);
+
+        let approvals = self.internal_count_approvals(&proposal);
+        let emergency = approvals >= self.emergency_threshold;
+
+        if !emergency {
+            if approvals < self.threshold {
+                env::panic_str("Not enough approvals");
+            }
+
+            if proposal.eta == 0 || Self::block_timestamp_seconds() < proposal.eta {
+                env::panic_str("Timelock not ready");
+            }
+        }
+
+        let gas = Gas(env::prepaid_gas()
+            .0
+            .saturating_sub(env::used_gas().0 + EXECUTE_GAS_RESERVE.0));
+
+        if gas < MINIMUM_PROPOSAL_GAS {
+            env::panic_str("Insufficient gas");
+        }
+
+        // Removed before the call, so the proposal cannot be executed twice in the meantime
+        self.proposals.remove(&proposal_id);
+
+        Promise::new(proposal.target.clone())
+            .function_call(
+                proposal.method.clone(),
+                proposal.args.0.clone(),
+                proposal.deposit.0,
+                gas,
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(ON_EXECUTE_GAS)
+                    .on_execute(proposal_id, proposal, executor, emergency),
+            )
+    }
+
+    // Callbacks
+
+    /// It emits a `ProposalExecutedEvent` if the proposal call succeeded, and restores the
+    /// proposal with its approvals if it failed
+    ///
+    /// Arguments:
+    ///
+    /// * `proposal_id`: The id of the proposal.
+    /// * `proposal`: The executed proposal.
+    /// * `executor`: The signer that executed the proposal.
+    /// * `emergency`: Whether the proposal was executed with the emergency threshold.
+    ///
+    /// Returns:
+    ///
+    /// Whether the proposal call succeeded.
+    #[private]
+    pub fn on_execute(
+        &mut self,
+        proposal_id: u64,
+        proposal: Proposal,
+        executor: AccountId,
+        emergency: bool,
+    ) -> bool {
+        if !matches!(env::promise_result(0), PromiseResult::Successful(_)) {
+            self.proposals.insert(&proposal_id, &proposal);
+
+            return false;
+        }
+
+        ProposalExecutedEvent {
+            proposal_id,
+            executor: executor.to_string(),
+            emergency,
+        }
+        .emit();
+
+        true
+    }
+
+    // Only self
+
+    /// `set_signers` replaces the signers and thresholds. It can only be called by the multisig
+    /// itself, through a proposal. Approvals of removed signers no longer count, and the delay of
+    /// every pending proposal starts over.
+    ///
+    /// Arguments:
+    ///
+    /// * `signers`: The new signers.
+    /// * `threshold`: The number of approvals needed to execute a call after the delay.
+    /// * `emergency_threshold`: The number of approvals needed to execute a call right away.
+    /// * `delay`: The time between a call reaching the threshold and its execution, in seconds.
+    #[private]
+    pub fn set_signers(
+        &mut self,
+        signers: Vec<AccountId>,
+        threshold: u64,
+        emergency_threshold: u64,
+        delay: u64,
+    ) {
+        self.internal_set_signers(signers, threshold, emergency_threshold, delay);
+    }
+
+    // Internal functions
+
+    fn require_signer(&self) -> AccountId {
+        let account_id = env::predecessor_account_id();
+
+        if !self.signers.contains(&account_id) {
+            env::panic_str("Not a signer");
+        }
+
+        account_id
+    }
+
+    fn internal_get_proposal(&self, proposal_id: u64) -> Proposal {
+        self.proposals
+            .get(&proposal_id)
+            .unwrap_or_else(|| env::panic_str("Unknown proposal"))
+    }
+
+    fn internal_count_approvals(&self, proposal: &Proposal) -> u64 {
+        proposal
+            .approvals
+            .iter()
+            .filter(|x| self.signers.contains(x))
+            .count() as u64
+    }
+
+    /// It stores a proposal after its approvals changed, starting the delay when it reaches the
+    /// threshold and stopping it when it falls below
+    ///
+    /// Arguments:
+    ///
+    /// * `proposal_id`: The id of the proposal.
+    /// * `proposal`: The proposal with its updated approvals.
+    /// * `signer`: The signer that approved or revoked.
+    /// * `approved`: Whether the signer approved the proposal.
+    fn internal_update_approvals(
+        &mut self,
+        proposal_id: u64,
+        mut proposal: Proposal,
+        signer: AccountId,
+        approved: bool,
+    ) {
+        let approvals = self.internal_count_approvals(&proposal);
+
+        if approvals < self.threshold {
+            proposal.eta = 0;
+        } else if proposal.eta == 0 {
+            proposal.eta = Self::block_timestamp_seconds() + self.delay;
+        }
+
+        ProposalApprovalsUpdatedEvent {
+            proposal_id,
+            signer: signer.to_string(),
+            approved,
+            approvals,
+            eta: proposal.eta,
+        }
+        .emit();
+
+        self.proposals.insert(&proposal_id, &proposal);
+    }
+
+    fn internal_set_signers(
+        &mut self,
+        mut signers: Vec<AccountId>,
+        threshold: u64,
+        emergency_threshold: u64,
+        delay: u64,
+    ) {
+        signers.sort();
+        signers.dedup();
+
+        if threshold == 0
+            || emergency_threshold < threshold
+            || emergency_threshold > signers.len() as u64
+        {
+            env::panic_str("Invalid threshold");
+        }
+
+        self.signers = signers;
+        self.threshold = threshold;
+        self.emergency_threshold = emergency_threshold;
+        self.delay = delay;
+
+        // Proposals may have reached or fallen below the new threshold, their delays start over
+        let eta = Self::block_timestamp_seconds() + delay;
+
+        for (proposal_id, mut proposal) in self.proposals.to_vec() {
+            proposal.eta = if self.internal_count_approvals(&proposal) < threshold {
+                0
+            } else {
+                eta
+            };
+
+            self.proposals.insert(&proposal_id, &proposal);
+        }
+
+        SignersUpdatedEvent {
+            signers: self.signers.iter().map(|x| x.to_string()).collect(),
+            threshold,
+            emergency_threshold,
+            delay,
+        }
+        .emit();
+    }
+
+    fn block_timestamp_seconds() -> u64 {
+        env::block_timestamp() / 1_000_000_000
+    }
+}